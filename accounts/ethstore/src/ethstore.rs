@@ -205,6 +205,16 @@ impl SimpleSecretStore for EthStore {
         self.store.change_vault_password(name, new_password)
     }
 
+    fn set_vault_password(
+        &self,
+        name: &str,
+        old_password: &Password,
+        new_password: &Password,
+    ) -> Result<(), Error> {
+        self.store
+            .set_vault_password(name, old_password, new_password)
+    }
+
     fn change_account_vault(
         &self,
         vault: SecretVaultRef,
@@ -569,6 +579,42 @@ impl EthMultiStore {
         }
         Ok(extended)
     }
+
+    /// Re-encrypts vault opened with `old_key` using `new_password`.
+    /// Vault which was opened before is reopened with the new password.
+    fn set_vault_key(
+        &self,
+        name: &str,
+        old_key: VaultKey,
+        new_password: &Password,
+    ) -> Result<(), Error> {
+        let vault_provider = self
+            .dir
+            .as_vault_provider()
+            .ok_or(Error::VaultsAreNotSupported)?;
+        let vault = vault_provider.open(name, old_key)?;
+        let is_opened = self.vaults.lock().contains_key(name);
+        let reopen = || {
+            if is_opened {
+                self.close_vault(name)
+                    .and_then(|_| self.open_vault(name, new_password))
+            } else {
+                Ok(())
+            }
+        };
+        match vault.set_key(VaultKey::new(new_password, self.iterations)) {
+            Ok(_) => reopen(),
+            Err(SetKeyError::Fatal(err)) => {
+                let _ = self.close_vault(name);
+                Err(err)
+            }
+            Err(SetKeyError::NonFatalNew(err)) => {
+                let _ = reopen();
+                Err(err)
+            }
+            Err(SetKeyError::NonFatalOld(err)) => Err(err),
+        }
+    }
 }
 
 impl SimpleSecretStore for EthMultiStore {
@@ -822,27 +868,20 @@ impl SimpleSecretStore for EthMultiStore {
             .get(name)
             .map(|v| v.key())
             .ok_or(Error::VaultNotFound)?;
-        let vault_provider = self
-            .dir
-            .as_vault_provider()
-            .ok_or(Error::VaultsAreNotSupported)?;
-        let vault = vault_provider.open(name, old_key)?;
-        match vault.set_key(VaultKey::new(new_password, self.iterations)) {
-            Ok(_) => self
-                .close_vault(name)
-                .and_then(|_| self.open_vault(name, new_password)),
-            Err(SetKeyError::Fatal(err)) => {
-                let _ = self.close_vault(name);
-                Err(err)
-            }
-            Err(SetKeyError::NonFatalNew(err)) => {
-                let _ = self
-                    .close_vault(name)
-                    .and_then(|_| self.open_vault(name, new_password));
-                Err(err)
-            }
-            Err(SetKeyError::NonFatalOld(err)) => Err(err),
-        }
+        self.set_vault_key(name, old_key, new_password)
+    }
+
+    fn set_vault_password(
+        &self,
+        name: &str,
+        old_password: &Password,
+        new_password: &Password,
+    ) -> Result<(), Error> {
+        self.set_vault_key(
+            name,
+            VaultKey::new(old_password, self.iterations),
+            new_password,
+        )
     }
 
     fn change_account_vault(
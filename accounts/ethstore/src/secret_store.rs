@@ -147,6 +147,13 @@ pub trait SimpleSecretStore: Send + Sync {
     fn list_opened_vaults(&self) -> Result<Vec<String>, Error>;
     /// Change vault password
     fn change_vault_password(&self, name: &str, new_password: &Password) -> Result<(), Error>;
+    /// Change vault password, authorizing with the current one. Vault doesn't need to be opened.
+    fn set_vault_password(
+        &self,
+        name: &str,
+        old_password: &Password,
+        new_password: &Password,
+    ) -> Result<(), Error>;
     /// Cnage account' vault
     fn change_account_vault(
         &self,
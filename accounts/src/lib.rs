@@ -32,7 +32,7 @@ use std::{
     time::{Duration, Instant},
 };
 
-use ethkey::{Address, Generator, Message, Password, Public, Random, Secret};
+use ethkey::{Address, Brain, Generator, Message, Password, Public, Random, Secret};
 use ethstore::{
     accounts_dir::MemoryDirectory, random_string, EthMultiStore, EthStore, OpaqueSecret,
     SecretStore, SecretVaultRef, SimpleSecretStore, StoreAccountRef,
@@ -74,6 +74,8 @@ pub struct AccountProvider {
     unlock_keep_secret: bool,
     /// Disallowed accounts.
     blacklisted_accounts: Vec<Address>,
+    /// Preferred account, listed first.
+    default_account: RwLock<Option<Address>>,
 }
 
 fn transient_sstore() -> EthMultiStore {
@@ -108,6 +110,7 @@ impl AccountProvider {
             transient_sstore: transient_sstore(),
             unlock_keep_secret: settings.unlock_keep_secret,
             blacklisted_accounts: settings.blacklisted_accounts,
+            default_account: RwLock::new(None),
         }
     }
 
@@ -124,6 +127,7 @@ impl AccountProvider {
             transient_sstore: transient_sstore(),
             unlock_keep_secret: false,
             blacklisted_accounts: vec![],
+            default_account: RwLock::new(None),
         }
     }
 
@@ -158,6 +162,18 @@ impl AccountProvider {
         Ok(account.address)
     }
 
+    /// Inserts account derived from a recovery phrase using the standard brainwallet mechanism.
+    pub fn insert_account_from_phrase(
+        &self,
+        phrase: String,
+        password: &Password,
+    ) -> Result<Address, Error> {
+        let brain = Brain::new(phrase)
+            .generate()
+            .expect("Brain::generate always returns Ok; qed");
+        self.insert_account(brain.secret().clone(), password)
+    }
+
     /// Generates new derived account based on the existing one
     /// If password is not provided, account must be unlocked
     /// New account will be created with the same password (if save: true)
@@ -216,14 +232,21 @@ impl AccountProvider {
         self.sstore.account_ref(&address).is_ok() && !self.blacklisted_accounts.contains(&address)
     }
 
-    /// Returns addresses of all accounts.
+    /// Returns addresses of all accounts, the default account first.
     pub fn accounts(&self) -> Result<Vec<Address>, Error> {
         let accounts = self.sstore.accounts()?;
-        Ok(accounts
+        let mut accounts: Vec<_> = accounts
             .into_iter()
             .map(|a| a.address)
             .filter(|address| !self.blacklisted_accounts.contains(address))
-            .collect())
+            .collect();
+        if let Some(default) = *self.default_account.read() {
+            if let Some(pos) = accounts.iter().position(|a| *a == default) {
+                let default = accounts.remove(pos);
+                accounts.insert(0, default);
+            }
+        }
+        Ok(accounts)
     }
 
     /// Returns the address of default account.
@@ -231,6 +254,15 @@ impl AccountProvider {
         Ok(self.accounts()?.first().cloned().unwrap_or_default())
     }
 
+    /// Sets the preferred account, returned by `default_account` and listed first by `accounts`.
+    pub fn set_default_account(&self, address: Address) -> Result<(), Error> {
+        if !self.has_account(address) {
+            return Err(Error::InvalidAccount);
+        }
+        *self.default_account.write() = Some(address);
+        Ok(())
+    }
+
     /// Returns each address along with metadata.
     pub fn addresses_info(&self) -> HashMap<Address, AccountMeta> {
         self.address_book.read().get()
@@ -597,6 +629,21 @@ impl AccountProvider {
         self.sstore.list_opened_vaults().map_err(Into::into)
     }
 
+    /// Returns addresses of accounts stored in an opened vault.
+    pub fn vault_accounts(&self, name: &str) -> Result<Vec<Address>, Error> {
+        if !self.list_opened_vaults()?.iter().any(|vault| vault == name) {
+            return Err(Error::VaultNotFound);
+        }
+        let vault = SecretVaultRef::Vault(name.to_owned());
+        Ok(self
+            .sstore
+            .accounts()?
+            .into_iter()
+            .filter(|a| a.vault == vault && !self.blacklisted_accounts.contains(&a.address))
+            .map(|a| a.address)
+            .collect())
+    }
+
     /// Change vault password.
     pub fn change_vault_password(&self, name: &str, new_password: &Password) -> Result<(), Error> {
         self.sstore
@@ -604,6 +651,19 @@ impl AccountProvider {
             .map_err(Into::into)
     }
 
+    /// Change vault password, authorizing with the current one.
+    /// Keys are re-encrypted atomically, so the vault is left untouched on failure.
+    pub fn set_vault_password(
+        &self,
+        name: &str,
+        old_password: &Password,
+        new_password: &Password,
+    ) -> Result<(), Error> {
+        self.sstore
+            .set_vault_password(name, old_password, new_password)
+            .map_err(Into::into)
+    }
+
     /// Change vault of the given address.
     pub fn change_vault(&self, address: Address, new_vault: &str) -> Result<(), Error> {
         let new_vault_ref = if new_vault.is_empty() {
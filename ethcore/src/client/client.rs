@@ -85,7 +85,7 @@ use factory::{Factories, VmFactory};
 use io::IoChannel;
 use miner::{Miner, MinerService};
 use snapshot::{self, io as snapshot_io, SnapshotClient};
-use spec::Spec;
+use spec::{ConsensusCapability, Spec};
 use state::{self, State};
 use state_db::StateDB;
 use stats::{prometheus, prometheus_counter, prometheus_gauge, PrometheusMetrics};
@@ -1481,6 +1481,7 @@ impl Client {
             env_info: &EnvInfo,
             machine: &::machine::EthereumMachine,
             state_diff: bool,
+            record_access: bool,
             transaction: &SignedTransaction,
             options: TransactOptions<T, V>,
         ) -> Result<Executed<T::Output, V::Output>, CallError>
@@ -1488,7 +1489,8 @@ impl Client {
             T: trace::Tracer,
             V: trace::VMTracer,
         {
-            let options = options.dont_check_nonce().save_output_from_contract();
+            let mut options = options.dont_check_nonce().save_output_from_contract();
+            options.record_access = record_access;
             let original_state = if state_diff {
                 Some(state.clone())
             } else {
@@ -1506,6 +1508,7 @@ impl Client {
         }
 
         let state_diff = analytics.state_diffing;
+        let record_access = analytics.access_recording;
 
         match (analytics.transaction_tracing, analytics.vm_tracing) {
             (true, true) => call(
@@ -1513,6 +1516,7 @@ impl Client {
                 env_info,
                 machine,
                 state_diff,
+                record_access,
                 t,
                 TransactOptions::with_tracing_and_vm_tracing(),
             ),
@@ -1521,6 +1525,7 @@ impl Client {
                 env_info,
                 machine,
                 state_diff,
+                record_access,
                 t,
                 TransactOptions::with_tracing(),
             ),
@@ -1529,6 +1534,7 @@ impl Client {
                 env_info,
                 machine,
                 state_diff,
+                record_access,
                 t,
                 TransactOptions::with_vm_tracing(),
             ),
@@ -1537,6 +1543,7 @@ impl Client {
                 env_info,
                 machine,
                 state_diff,
+                record_access,
                 t,
                 TransactOptions::with_no_tracing(),
             ),
@@ -1889,7 +1896,7 @@ impl Call for Client {
     ) -> Result<U256, CallError> {
         let (mut upper, max_upper, env_info) = {
             let init = *header.gas_limit();
-            let max = init * U256::from(10);
+            let max = self.config.estimate_gas_cap.unwrap_or(init);
 
             let env_info = EnvInfo {
                 number: header.number(),
@@ -1901,7 +1908,7 @@ impl Call for Client {
                 gas_limit: max,
             };
 
-            (init, max, env_info)
+            (cmp::min(init, max), max, env_info)
         };
 
         let sender = t.sender();
@@ -1921,7 +1928,7 @@ impl Call for Client {
 
         let cond = |gas| exec(gas).ok().map_or(false, |r| r.exception.is_none());
 
-        if !cond(upper) {
+        if upper == max_upper || !cond(upper) {
             upper = max_upper;
             match exec(upper) {
                 Ok(v) => {
@@ -1951,11 +1958,27 @@ impl Call for Client {
         /// Find transition point between `lower` and `upper` where `cond` changes from `false` to `true`.
         /// Returns the lowest value between `lower` and `upper` for which `cond` returns true.
         /// We assert: `cond(lower) = false`, `cond(upper) = true`
-        fn binary_chop<F, E>(mut lower: U256, mut upper: U256, mut cond: F) -> Result<U256, E>
+        /// Gives up once `max_probes` values were tried without finding the transition point.
+        fn binary_chop<F>(
+            mut lower: U256,
+            mut upper: U256,
+            max_probes: usize,
+            mut cond: F,
+        ) -> Result<U256, CallError>
         where
             F: FnMut(U256) -> bool,
         {
+            let mut probes = 0;
             while upper - lower > 1.into() {
+                if probes == max_probes {
+                    trace!(target: "estimate_gas", "estimate_gas gave up after {} probes", probes);
+                    let err = ExecutionError::Internal(format!(
+                        "Gas estimation did not converge within {} probes",
+                        max_probes
+                    ));
+                    return Err(err.into());
+                }
+                probes += 1;
                 let mid = (lower + upper) / 2;
                 trace!(target: "estimate_gas", "{} .. {} .. {}", lower, mid, upper);
                 let c = cond(mid);
@@ -1970,7 +1993,7 @@ impl Call for Client {
 
         // binary chop to non-excepting call with gas somewhere between 21000 and block gas limit
         trace!(target: "estimate_gas", "estimate_gas chopping {} .. {}", lower, upper);
-        binary_chop(lower, upper, cond)
+        binary_chop(lower, upper, self.config.estimate_gas_max_probes, cond)
     }
 }
 
@@ -2147,6 +2170,23 @@ impl BlockChainClient for Client {
         }
     }
 
+    fn storage_at_many(
+        &self,
+        address: &Address,
+        positions: &[H256],
+        state: StateOrBlock,
+    ) -> Option<Vec<H256>> {
+        let state = match state {
+            StateOrBlock::State(s) => s,
+            StateOrBlock::Block(id) => Box::new(self.state_at(id)?) as Box<dyn StateInfo>,
+        };
+
+        positions
+            .iter()
+            .map(|position| state.storage_at(address, position).ok())
+            .collect()
+    }
+
     fn list_accounts(
         &self,
         id: BlockId,
@@ -2360,6 +2400,12 @@ impl BlockChainClient for Client {
         self.engine.additional_params().into_iter().collect()
     }
 
+    fn consensus_capability(&self) -> ConsensusCapability {
+        self.engine
+            .params()
+            .consensus_capability(self.chain.read().best_block_number())
+    }
+
     fn logs(&self, filter: Filter) -> Result<Vec<LocalizedLogEntry>, BlockId> {
         let chain = self.chain.read();
 
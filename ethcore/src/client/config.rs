@@ -19,6 +19,7 @@ use std::{
     str::FromStr,
 };
 
+use ethereum_types::U256;
 use journaldb;
 use snapshot::SnapshotConfiguration;
 use verification::{QueueConfig, VerifierType};
@@ -127,6 +128,10 @@ pub struct ClientConfig {
     pub max_round_blocks_to_import: usize,
     /// Snapshot configuration
     pub snapshot: SnapshotConfiguration,
+    /// Maximal gas `estimate_gas` is allowed to probe with. Defaults to the block gas limit.
+    pub estimate_gas_cap: Option<U256>,
+    /// Maximal number of probes `estimate_gas` runs while searching for the lowest gas.
+    pub estimate_gas_max_probes: usize,
 }
 
 impl Default for ClientConfig {
@@ -153,6 +158,8 @@ impl Default for ClientConfig {
             transaction_verification_queue_size: 8192,
             max_round_blocks_to_import: 1,
             snapshot: Default::default(),
+            estimate_gas_cap: None,
+            estimate_gas_max_probes: 64,
         }
     }
 }
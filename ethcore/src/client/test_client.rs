@@ -29,9 +29,9 @@ use bytes::Bytes;
 use db::{COL_STATE, NUM_COLUMNS};
 use ethcore_miner::pool::VerifiedTransaction;
 use ethereum_types::{Address, H256, U256};
-use ethkey::{Generator, Random};
+use ethkey::{self, Generator, KeyPair, Random};
 use ethtrie;
-use hash::keccak;
+use hash::{keccak, KECCAK_EMPTY, KECCAK_NULL_RLP};
 use itertools::Itertools;
 use kvdb::DBValue;
 use kvdb_memorydb;
@@ -72,7 +72,7 @@ use executed::CallError;
 use executive::Executed;
 use journaldb;
 use miner::{self, Miner, MinerService};
-use spec::Spec;
+use spec::{ConsensusCapability, Spec};
 use state::StateInfo;
 use state_db::StateDB;
 use stats::{prometheus, PrometheusMetrics};
@@ -109,6 +109,8 @@ pub struct TestBlockChainClient {
     pub logs: RwLock<Vec<LocalizedLogEntry>>,
     /// Should return errors on logs.
     pub error_on_logs: RwLock<Option<BlockId>>,
+    /// Number of times logs were queried.
+    pub logs_queries: AtomicUsize,
     /// Block queue size.
     pub queue_size: AtomicUsize,
     /// Miner
@@ -125,6 +127,8 @@ pub struct TestBlockChainClient {
     pub traces: RwLock<Option<Vec<LocalizedTrace>>>,
     /// Pruning history size to report.
     pub history: RwLock<Option<u64>>,
+    /// Name of the chain.
+    pub spec_name: RwLock<String>,
     /// Is disabled
     pub disabled: AtomicBool,
 }
@@ -136,6 +140,8 @@ pub enum EachBlockWith {
     Nothing,
     /// Block with an uncle.
     Uncle,
+    /// Block with multiple uncles.
+    Uncles(usize),
     /// Block with a transaction.
     Transaction,
     /// Block with multiple transactions.
@@ -194,8 +200,10 @@ impl TestBlockChainClient {
             first_block: RwLock::new(None),
             traces: RwLock::new(None),
             history: RwLock::new(None),
+            spec_name: RwLock::new("foundation".into()),
             disabled: AtomicBool::new(false),
             error_on_logs: RwLock::new(None),
+            logs_queries: AtomicUsize::new(0),
         };
 
         // insert genesis hash.
@@ -273,13 +281,23 @@ impl TestBlockChainClient {
         header = hook(header);
 
         let uncles = match with {
-            EachBlockWith::Uncle | EachBlockWith::UncleAndTransaction => {
-                let mut uncles = RlpStream::new_list(1);
-                let mut uncle_header = Header::new();
-                uncle_header.set_difficulty(From::from(n));
-                uncle_header.set_parent_hash(self.last_hash.read().clone());
-                uncle_header.set_number(n as BlockNumber);
-                uncles.append(&uncle_header);
+            EachBlockWith::Uncle
+            | EachBlockWith::UncleAndTransaction
+            | EachBlockWith::Uncles(_) => {
+                let num_uncles = match with {
+                    EachBlockWith::Uncles(num) => num,
+                    _ => 1,
+                };
+                let mut uncles = RlpStream::new_list(num_uncles);
+                for i in 0..num_uncles {
+                    let mut uncle_header = Header::new();
+                    uncle_header.set_difficulty(From::from(n));
+                    uncle_header.set_parent_hash(self.last_hash.read().clone());
+                    uncle_header.set_number(n as BlockNumber);
+                    // make sure sibling uncles have distinct hashes
+                    uncle_header.set_timestamp(i as u64);
+                    uncles.append(&uncle_header);
+                }
                 header.set_uncles_hash(keccak(uncles.as_raw()));
                 uncles
             }
@@ -326,6 +344,19 @@ impl TestBlockChainClient {
         self.import_block(unverified).unwrap();
     }
 
+    /// Add a block sealed Clique-style by `signer`: the signature over the header
+    /// is appended to the 32-byte vanity in `extra_data`, leaving `author` empty.
+    pub fn add_clique_sealed_block(&self, signer: &KeyPair) {
+        self.add_block(EachBlockWith::Nothing, |mut header| {
+            let mut extra_data = vec![0u8; 32];
+            header.set_extra_data(extra_data.clone());
+            let signature = ethkey::sign(signer.secret(), &header.hash()).unwrap();
+            extra_data.extend_from_slice(&signature[..]);
+            header.set_extra_data(extra_data);
+            header
+        });
+    }
+
     /// Add a sequence of blocks to test client.
     pub fn add_blocks(&self, count: usize, with: EachBlockWith) {
         for _ in 0..count {
@@ -333,6 +364,11 @@ impl TestBlockChainClient {
         }
     }
 
+    /// Get hashes of the uncles included in given block.
+    pub fn uncle_hashes(&self, id: BlockId) -> Option<Vec<H256>> {
+        BlockChainClient::block(self, id).map(|block| block.uncle_hashes())
+    }
+
     /// Make a bad block by setting invalid parent hash.
     pub fn corrupt_block_parent(&self, n: BlockNumber) {
         let hash = self.block_hash(BlockId::Number(n)).unwrap();
@@ -720,7 +756,7 @@ impl StateClient for TestBlockChainClient {
 
 impl EngineInfo for TestBlockChainClient {
     fn engine(&self) -> &dyn EthEngine {
-        unimplemented!()
+        &*self.spec.engine
     }
 }
 
@@ -791,6 +827,31 @@ impl BlockChainClient for TestBlockChainClient {
         }
     }
 
+    fn storage_at_many(
+        &self,
+        address: &Address,
+        positions: &[H256],
+        state: StateOrBlock,
+    ) -> Option<Vec<H256>> {
+        match state {
+            StateOrBlock::Block(BlockId::Latest) => {
+                let storage = self.storage.read();
+                Some(
+                    positions
+                        .iter()
+                        .map(|position| {
+                            storage
+                                .get(&(address.clone(), position.clone()))
+                                .cloned()
+                                .unwrap_or_else(H256::new)
+                        })
+                        .collect(),
+                )
+            }
+            _ => None,
+        }
+    }
+
     fn list_accounts(
         &self,
         _id: BlockId,
@@ -830,12 +891,31 @@ impl BlockChainClient for TestBlockChainClient {
     }
 
     fn logs(&self, filter: Filter) -> Result<Vec<LocalizedLogEntry>, BlockId> {
+        self.logs_queries.fetch_add(1, AtomicOrder::Relaxed);
         match self.error_on_logs.read().as_ref() {
             Some(id) => return Err(id.clone()),
             None => (),
         }
 
         let mut logs = self.logs.read().clone();
+        match (filter.from_block, filter.to_block) {
+            (BlockId::Hash(from), BlockId::Hash(to)) if from == to => {
+                logs.retain(|log| log.block_hash == from && filter.matches(&log.entry))
+            }
+            (from, to) => {
+                // mocked logs may lie past the mocked chain, so `latest` leaves the range open
+                let bound = |id| match id {
+                    BlockId::Latest => None,
+                    id => self.block_number(id),
+                };
+                let (from, to) = (bound(from), bound(to));
+                logs.retain(|log| {
+                    from.map_or(true, |from| log.block_number >= from)
+                        && to.map_or(true, |to| log.block_number <= to)
+                        && filter.matches(&log.entry)
+                })
+            }
+        }
         let len = logs.len();
         Ok(match filter.limit {
             Some(limit) if limit <= len => logs.split_off(len - limit),
@@ -968,7 +1048,13 @@ impl BlockChainClient for TestBlockChainClient {
     fn clear_queue(&self) {}
 
     fn additional_params(&self) -> BTreeMap<String, String> {
-        Default::default()
+        self.spec.engine.additional_params().into_iter().collect()
+    }
+
+    fn consensus_capability(&self) -> ConsensusCapability {
+        self.spec
+            .params()
+            .consensus_capability(self.chain_info().best_block_number)
     }
 
     fn filter_traces(&self, _filter: TraceFilter) -> Option<Vec<LocalizedTrace>> {
@@ -1008,7 +1094,7 @@ impl BlockChainClient for TestBlockChainClient {
     }
 
     fn spec_name(&self) -> String {
-        "foundation".into()
+        self.spec_name.read().clone()
     }
 
     fn set_spec_name(&self, _: String) -> Result<(), ()> {
@@ -1080,8 +1166,35 @@ impl ProvingBlockChainClient for TestBlockChainClient {
         None
     }
 
-    fn prove_account(&self, _: H256, _: BlockId) -> Option<(Vec<Bytes>, BasicAccount)> {
-        None
+    fn prove_account(&self, key1: H256, id: BlockId) -> Option<(Vec<Bytes>, BasicAccount)> {
+        if id != BlockId::Latest {
+            return None;
+        }
+
+        let balances = self.balances.read();
+        let nonces = self.nonces.read();
+        let code = self.code.read();
+        let address = balances
+            .keys()
+            .chain(nonces.keys())
+            .chain(code.keys())
+            .find(|address| keccak(address) == key1)
+            .cloned();
+
+        let account = BasicAccount {
+            balance: address
+                .and_then(|a| balances.get(&a).cloned())
+                .unwrap_or_default(),
+            nonce: address
+                .and_then(|a| nonces.get(&a).cloned())
+                .unwrap_or(self.spec.params().account_start_nonce),
+            code_hash: address
+                .and_then(|a| code.get(&a).map(keccak))
+                .unwrap_or(KECCAK_EMPTY),
+            storage_root: KECCAK_NULL_RLP,
+        };
+
+        Some((Vec::new(), account))
     }
 
     fn prove_transaction(&self, _: SignedTransaction, _: BlockId) -> Option<(Bytes, Vec<DBValue>)> {
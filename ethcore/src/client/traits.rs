@@ -51,6 +51,7 @@ use engines::EthEngine;
 use error::{Error, EthcoreResult};
 use executed::CallError;
 use executive::Executed;
+use spec::ConsensusCapability;
 use state::StateInfo;
 use trace::LocalizedTrace;
 use verification::queue::{kind::blocks::Unverified, QueueInfo as BlockQueueInfo};
@@ -279,6 +280,15 @@ pub trait BlockChainClient:
     /// Returns None if and only if the block's root hash has been pruned from the DB.
     fn storage_at(&self, address: &Address, position: &H256, state: StateOrBlock) -> Option<H256>;
 
+    /// Get values of the storage at given positions, all read from a single state.
+    /// Returns `None` if the state is not available.
+    fn storage_at_many(
+        &self,
+        address: &Address,
+        positions: &[H256],
+        state: StateOrBlock,
+    ) -> Option<Vec<H256>>;
+
     /// Get value of the storage at given position at the latest block's state.
     fn latest_storage_at(&self, address: &Address, position: &H256) -> H256 {
         self.storage_at(address, position, BlockId::Latest.into())
@@ -341,6 +351,9 @@ pub trait BlockChainClient:
     /// Get the registrar address, if it exists.
     fn additional_params(&self) -> BTreeMap<String, String>;
 
+    /// Whether the node is able to follow the forks announced by the chain spec at the best block.
+    fn consensus_capability(&self) -> ConsensusCapability;
+
     /// Returns logs matching given filter. If one of the filtering block cannot be found, returns the block id that caused the error.
     fn logs(&self, filter: Filter) -> Result<Vec<LocalizedLogEntry>, BlockId>;
 
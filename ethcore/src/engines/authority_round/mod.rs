@@ -38,7 +38,7 @@ use block::*;
 use client::{traits::ForceUpdateSealing, EngineClient};
 use engines::{
     block_reward,
-    block_reward::{BlockRewardContract, RewardKind},
+    block_reward::{BlockRewardContract, BlockRewards, RewardKind},
     ConstructedVerifier, Engine, EngineError, Seal,
 };
 use error::{BlockError, Error, ErrorKind};
@@ -1393,6 +1393,18 @@ impl Engine<EthereumMachine> for AuthorityRound {
         block_reward::apply_block_rewards(&rewards, block, &self.machine)
     }
 
+    fn block_rewards(&self, header: &Header, uncles: &[Header]) -> Option<BlockRewards> {
+        match self.block_reward_contract {
+            Some(_) if header.number() >= self.block_reward_contract_transition => None,
+            // uncles are not rewarded; empty step authors get `block_reward` on top, see `on_close_block`
+            _ => Some(BlockRewards {
+                base: self.block_reward,
+                uncle_inclusion: U256::zero(),
+                uncles: vec![U256::zero(); uncles.len()],
+            }),
+        }
+    }
+
     /// Check the number of seal fields.
     fn verify_block_basic(&self, header: &Header) -> Result<(), Error> {
         if header.number() >= self.validate_score_transition
@@ -2550,6 +2562,22 @@ mod tests {
         assert!(engine.verify_block_family(&header, &parent_header).is_ok());
     }
 
+    #[test]
+    fn block_rewards_are_static_without_contract() {
+        let spec = Spec::new_test_round_empty_steps();
+        let mut header = Header::new();
+        header.set_number(1);
+        let uncle = Header::new();
+
+        let rewards = spec.engine.block_rewards(&header, &[uncle]).unwrap();
+        assert_eq!(rewards.base, U256::from(10));
+        assert_eq!(rewards.uncle_inclusion, U256::zero());
+        assert_eq!(rewards.uncles, vec![U256::zero()]);
+
+        let spec = Spec::new_test_round_block_reward_contract();
+        assert_eq!(spec.engine.block_rewards(&header, &[]), None);
+    }
+
     #[test]
     fn block_reward_contract() {
         let spec = Spec::new_test_round_block_reward_contract();
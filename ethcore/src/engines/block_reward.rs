@@ -82,6 +82,17 @@ impl Into<trace::RewardType> for RewardKind {
     }
 }
 
+/// Static rewards bestowed by an engine when closing a block.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct BlockRewards {
+    /// Base reward of the block author.
+    pub base: U256,
+    /// Additional reward of the block author for including uncles.
+    pub uncle_inclusion: U256,
+    /// Rewards of the uncle authors, in order of inclusion.
+    pub uncles: Vec<U256>,
+}
+
 /// A client for the block reward contract.
 #[derive(PartialEq, Debug)]
 pub struct BlockRewardContract {
@@ -324,6 +324,16 @@ pub trait Engine<M: Machine>: Sync + Send {
         Ok(())
     }
 
+    /// Static rewards bestowed when closing a block with given header and uncles.
+    /// Returns `None` if the engine does not know them upfront, e.g. they are decided by a contract.
+    fn block_rewards(
+        &self,
+        _header: &Header,
+        _uncles: &[Header],
+    ) -> Option<block_reward::BlockRewards> {
+        None
+    }
+
     /// Allow mutating the header during seal generation. Currently only used by Clique.
     fn on_seal_block(&self, _block: &mut ExecutedBlock) -> Result<(), Error> {
         Ok(())
@@ -16,7 +16,7 @@
 
 use block::ExecutedBlock;
 use engines::{
-    block_reward::{self, RewardKind},
+    block_reward::{self, BlockRewards, RewardKind},
     Engine,
 };
 use ethereum_types::U256;
@@ -77,30 +77,27 @@ impl<M: Machine> Engine<M> for NullEngine<M> {
     }
 
     fn on_close_block(&self, block: &mut ExecutedBlock) -> Result<(), M::Error> {
-        use std::ops::Shr;
-
         let author = *block.header.author();
         let number = block.header.number();
 
-        let reward = self.params.block_reward;
-        if reward == U256::zero() {
+        if self.params.block_reward == U256::zero() {
             return Ok(());
         }
 
-        let n_uncles = block.uncles.len();
+        let block_rewards = self
+            .block_rewards(&block.header, &block.uncles)
+            .expect("NullEngine rewards are always static; qed");
 
         let mut rewards = Vec::new();
 
         // Bestow block reward
-        let result_block_reward = reward + reward.shr(5) * U256::from(n_uncles);
+        let result_block_reward = block_rewards.base + block_rewards.uncle_inclusion;
         rewards.push((author, RewardKind::Author, result_block_reward));
 
         // bestow uncle rewards.
-        for u in &block.uncles {
-            let uncle_author = u.author();
-            let result_uncle_reward = (reward * U256::from(8 + u.number() - number)).shr(3);
+        for (u, result_uncle_reward) in block.uncles.iter().zip(block_rewards.uncles) {
             rewards.push((
-                *uncle_author,
+                *u.author(),
                 RewardKind::uncle(number, u.number()),
                 result_uncle_reward,
             ));
@@ -109,6 +106,20 @@ impl<M: Machine> Engine<M> for NullEngine<M> {
         block_reward::apply_block_rewards(&rewards, block, &self.machine)
     }
 
+    fn block_rewards(&self, header: &Header, uncles: &[Header]) -> Option<BlockRewards> {
+        use std::ops::Shr;
+
+        let reward = self.params.block_reward;
+        Some(BlockRewards {
+            base: reward,
+            uncle_inclusion: reward.shr(5) * U256::from(uncles.len()),
+            uncles: uncles
+                .iter()
+                .map(|u| (reward * U256::from(8 + u.number() - header.number())).shr(3))
+                .collect(),
+        })
+    }
+
     fn maximum_uncle_count(&self, _block: BlockNumber) -> usize {
         2
     }
@@ -29,7 +29,7 @@ use unexpected::{Mismatch, OutOfBounds};
 use block::ExecutedBlock;
 use engines::{
     self,
-    block_reward::{self, BlockRewardContract, RewardKind},
+    block_reward::{self, BlockRewardContract, BlockRewards, RewardKind},
     Engine,
 };
 use error::{BlockError, Error};
@@ -258,8 +258,6 @@ impl Engine<EthereumMachine> for Arc<Ethash> {
     /// Apply the block reward on finalisation of the block.
     /// This assumes that all uncles are valid uncles (i.e. of at least one generation before the current).
     fn on_close_block(&self, block: &mut ExecutedBlock) -> Result<(), Error> {
-        use std::ops::Shr;
-
         let author = *block.header.author();
         let number = block.header.number();
 
@@ -282,49 +280,31 @@ impl Engine<EthereumMachine> for Arc<Ethash> {
                     .collect()
             }
             _ => {
-                let mut rewards = Vec::new();
-
-                let (_, reward) = self.ethash_params.block_reward.iter()
-					.rev()
-					.find(|&(block, _)| *block <= number)
-					.expect("Current block's reward is not found; this indicates a chain config error; qed");
-                let reward = *reward;
-
-                // Applies ECIP-1017 eras.
-                let eras_rounds = self.ethash_params.ecip1017_era_rounds;
-                let (eras, reward) = ecip1017_eras_block_reward(eras_rounds, reward, number);
-
-                //let n_uncles = LiveBlock::uncles(&*block).len();
-                let n_uncles = block.uncles.len();
-
-                // Bestow block rewards.
-                let result_block_reward = reward + reward.shr(5) * U256::from(n_uncles);
-
-                rewards.push((author, RewardKind::Author, result_block_reward));
-
-                // Bestow uncle rewards.
-                for u in &block.uncles {
-                    let uncle_author = u.author();
-                    let result_uncle_reward = if eras == 0 {
-                        (reward * U256::from(8 + u.number() - number)).shr(3)
-                    } else {
-                        reward.shr(5)
-                    };
-
-                    rewards.push((
-                        *uncle_author,
-                        RewardKind::uncle(number, u.number()),
-                        result_uncle_reward,
-                    ));
+                let rewards = self.static_block_rewards(number, &block.uncles);
+                let mut result = vec![(
+                    author,
+                    RewardKind::Author,
+                    rewards.base + rewards.uncle_inclusion,
+                )];
+                for (u, reward) in block.uncles.iter().zip(rewards.uncles) {
+                    result.push((*u.author(), RewardKind::uncle(number, u.number()), reward));
                 }
-
-                rewards
+                result
             }
         };
 
         block_reward::apply_block_rewards(&rewards, block, &self.machine)
     }
 
+    fn block_rewards(&self, header: &Header, uncles: &[Header]) -> Option<BlockRewards> {
+        match self.ethash_params.block_reward_contract {
+            Some(_) if header.number() >= self.ethash_params.block_reward_contract_transition => {
+                None
+            }
+            _ => Some(self.static_block_rewards(header.number(), uncles)),
+        }
+    }
+
     #[cfg(not(feature = "miner-debug"))]
     fn verify_local_seal(&self, header: &Header) -> Result<(), Error> {
         self.verify_block_basic(header)
@@ -447,6 +427,40 @@ impl Engine<EthereumMachine> for Arc<Ethash> {
 }
 
 impl Ethash {
+    fn static_block_rewards(&self, number: BlockNumber, uncles: &[Header]) -> BlockRewards {
+        use std::ops::Shr;
+
+        let (_, reward) = self
+            .ethash_params
+            .block_reward
+            .iter()
+            .rev()
+            .find(|&(block, _)| *block <= number)
+            .expect(
+                "Current block's reward is not found; this indicates a chain config error; qed",
+            );
+        let reward = *reward;
+
+        // Applies ECIP-1017 eras.
+        let eras_rounds = self.ethash_params.ecip1017_era_rounds;
+        let (eras, reward) = ecip1017_eras_block_reward(eras_rounds, reward, number);
+
+        BlockRewards {
+            base: reward,
+            uncle_inclusion: reward.shr(5) * U256::from(uncles.len()),
+            uncles: uncles
+                .iter()
+                .map(|u| {
+                    if eras == 0 {
+                        (reward * U256::from(8 + u.number() - number)).shr(3)
+                    } else {
+                        reward.shr(5)
+                    }
+                })
+                .collect(),
+        }
+    }
+
     fn calculate_difficulty(&self, header: &Header, parent: &Header) -> U256 {
         const EXP_DIFF_PERIOD: u64 = 100_000;
         if header.number() == 0 {
@@ -698,6 +712,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn block_rewards_with_uncle() {
+        let spec = test_spec();
+        let engine = &*spec.engine;
+        let mut header = Header::new();
+        header.set_number(1);
+        let uncle = Header::new();
+
+        let rewards = engine.block_rewards(&header, &[uncle]).unwrap();
+        assert_eq!(rewards.base, "4563918244f40000".into());
+        assert_eq!(rewards.uncle_inclusion, "022b1c8c1227a000".into());
+        assert_eq!(rewards.uncles, vec!["3cb71f51fc558000".into()]);
+    }
+
     #[test]
     fn has_valid_mcip3_era_block_rewards() {
         let spec = new_mcip3_test();
@@ -301,6 +301,11 @@ pub fn new_istanbul_test_machine() -> EthereumMachine {
     load_machine(include_bytes!("../../res/ethereum/istanbul_test.json"))
 }
 
+/// Create a new YOLO machine.
+pub fn new_yolo3_test_machine() -> EthereumMachine {
+    load_machine(include_bytes!("../../res/ethereum/yolo3_test.json"))
+}
+
 /// Create a new Musicoin-MCIP3-era spec.
 pub fn new_mcip3_test_machine() -> EthereumMachine {
     load_machine(include_bytes!("../../res/ethereum/mcip3_test.json"))
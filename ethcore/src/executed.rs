@@ -17,13 +17,16 @@
 //! Transaction execution format module.
 
 use bytes::Bytes;
-use ethereum_types::{Address, U256, U512};
+use ethereum_types::{Address, H256, U256, U512};
 use ethtrie;
 use trace::{FlatTrace, VMTrace};
 use types::{log_entry::LogEntry, state_diff::StateDiff};
 use vm;
 
-use std::{error, fmt};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error, fmt,
+};
 
 /// Transaction execution receipt.
 #[derive(Debug, PartialEq, Clone)]
@@ -66,6 +69,11 @@ pub struct Executed<T = FlatTrace, V = VMTrace> {
     pub vm_trace: Option<V>,
     /// The state diff, if we traced it.
     pub state_diff: Option<StateDiff>,
+    /// Accounts and storage keys accessed during execution, as recorded by the EIP-2929
+    /// access list. Only filled in when requested through `TransactOptions::record_access`,
+    /// and always empty before EIP-2929 is enabled. Precompiled contracts are always warm,
+    /// so they are left out.
+    pub accessed: BTreeMap<Address, BTreeSet<H256>>,
 }
 
 /// Result of executing the transaction.
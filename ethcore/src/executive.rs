@@ -25,7 +25,11 @@ use factory::VmFactory;
 use hash::keccak;
 use machine::EthereumMachine as Machine;
 use state::{Backend as StateBackend, CleanupMode, State, Substate};
-use std::{cmp, sync::Arc};
+use std::{
+    cmp,
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 use trace::{self, Tracer, VMTracer};
 use transaction_ext::Transaction;
 use types::transaction::{Action, SignedTransaction, TypedTransaction};
@@ -131,6 +135,8 @@ pub struct TransactOptions<T, V> {
     pub check_nonce: bool,
     /// Records the output from init contract calls.
     pub output_from_init_contract: bool,
+    /// Records the accounts and storage keys accessed during execution.
+    pub record_access: bool,
 }
 
 impl<T, V> TransactOptions<T, V> {
@@ -141,6 +147,7 @@ impl<T, V> TransactOptions<T, V> {
             vm_tracer,
             check_nonce: true,
             output_from_init_contract: false,
+            record_access: false,
         }
     }
 
@@ -155,6 +162,12 @@ impl<T, V> TransactOptions<T, V> {
         self.output_from_init_contract = true;
         self
     }
+
+    /// Reports the accounts and storage keys accessed during execution in `Executed::accessed`.
+    pub fn record_access(mut self) -> Self {
+        self.record_access = true;
+        self
+    }
 }
 
 impl TransactOptions<trace::ExecutiveTracer, trace::ExecutiveVMTracer> {
@@ -165,6 +178,7 @@ impl TransactOptions<trace::ExecutiveTracer, trace::ExecutiveVMTracer> {
             vm_tracer: trace::ExecutiveVMTracer::toplevel(),
             check_nonce: true,
             output_from_init_contract: false,
+            record_access: false,
         }
     }
 }
@@ -177,6 +191,7 @@ impl TransactOptions<trace::ExecutiveTracer, trace::NoopVMTracer> {
             vm_tracer: trace::NoopVMTracer,
             check_nonce: true,
             output_from_init_contract: false,
+            record_access: false,
         }
     }
 }
@@ -189,6 +204,7 @@ impl TransactOptions<trace::NoopTracer, trace::ExecutiveVMTracer> {
             vm_tracer: trace::ExecutiveVMTracer::toplevel(),
             check_nonce: true,
             output_from_init_contract: false,
+            record_access: false,
         }
     }
 }
@@ -201,6 +217,7 @@ impl TransactOptions<trace::NoopTracer, trace::NoopVMTracer> {
             vm_tracer: trace::NoopVMTracer,
             check_nonce: true,
             output_from_init_contract: false,
+            record_access: false,
         }
     }
 }
@@ -1090,6 +1107,7 @@ impl<'a, B: 'a + StateBackend> Executive<'a, B> {
             t,
             options.check_nonce,
             options.output_from_init_contract,
+            options.record_access,
             options.tracer,
             options.vm_tracer,
         )
@@ -1128,6 +1146,7 @@ impl<'a, B: 'a + StateBackend> Executive<'a, B> {
         t: &SignedTransaction,
         check_nonce: bool,
         output_from_create: bool,
+        record_access: bool,
         mut tracer: T,
         mut vm_tracer: V,
     ) -> Result<Executed<T::Output, V::Output>, ExecutionError>
@@ -1299,6 +1318,7 @@ impl<'a, B: 'a + StateBackend> Executive<'a, B> {
             output,
             tracer.drain(),
             vm_tracer.drain(),
+            record_access,
         )?)
     }
 
@@ -1457,6 +1477,7 @@ impl<'a, B: 'a + StateBackend> Executive<'a, B> {
         output: Bytes,
         trace: Vec<T>,
         vm_trace: Option<V>,
+        record_access: bool,
     ) -> Result<Executed<T, V>, ExecutionError> {
         let schedule = self.schedule;
 
@@ -1533,6 +1554,22 @@ impl<'a, B: 'a + StateBackend> Executive<'a, B> {
             schedule.kill_dust == CleanDustMode::WithCodeAndStorage,
         )?;
 
+        let mut accessed = BTreeMap::new();
+        if record_access {
+            let builtins = self.machine.builtins();
+            for address in substate.access_list.addresses() {
+                if !builtins.contains_key(&address) {
+                    accessed.entry(address).or_insert_with(BTreeSet::new);
+                }
+            }
+            for (address, key) in substate.access_list.storage_keys() {
+                accessed
+                    .entry(address)
+                    .or_insert_with(BTreeSet::new)
+                    .insert(key);
+            }
+        }
+
         match result {
             Err(vm::Error::Internal(msg)) => Err(ExecutionError::Internal(msg)),
             Err(exception) => Ok(Executed {
@@ -1547,6 +1584,7 @@ impl<'a, B: 'a + StateBackend> Executive<'a, B> {
                 trace: trace,
                 vm_trace: vm_trace,
                 state_diff: None,
+                accessed: accessed,
             }),
             Ok(r) => Ok(Executed {
                 exception: if r.apply_state {
@@ -1564,6 +1602,7 @@ impl<'a, B: 'a + StateBackend> Executive<'a, B> {
                 trace: trace,
                 vm_trace: vm_trace,
                 state_diff: None,
+                accessed: accessed,
             }),
         }
     }
@@ -2528,6 +2567,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_transact_records_accessed_accounts_and_storage() {
+        let keypair = Random.generate().unwrap();
+        let contract = Address::from(0x100);
+        let t = TypedTransaction::Legacy(Transaction {
+            action: Action::Call(contract),
+            value: U256::zero(),
+            data: vec![],
+            gas: U256::from(100_000),
+            gas_price: U256::zero(),
+            nonce: U256::zero(),
+        })
+        .sign(keypair.secret(), None);
+        let sender = t.sender();
+
+        let mut state = get_temp_state();
+        // PUSH1 0 SLOAD POP PUSH1 1 PUSH1 1 SSTORE STOP
+        state
+            .init_code(&contract, "60005450600160015500".from_hex().unwrap())
+            .unwrap();
+        let mut info = EnvInfo::default();
+        info.gas_limit = U256::from(100_000);
+        let machine = ::ethereum::new_yolo3_test_machine();
+        let schedule = machine.schedule(info.number);
+
+        let executed = {
+            let mut ex = Executive::new(&mut state, &info, &machine, &schedule);
+            let opts = TransactOptions::with_no_tracing().record_access();
+            ex.transact(&t, opts).unwrap()
+        };
+
+        assert_eq!(executed.exception, None);
+        assert_eq!(
+            executed.accessed.get(&contract),
+            Some(&vec![H256::from(0), H256::from(1)].into_iter().collect())
+        );
+        assert_eq!(executed.accessed.get(&sender), Some(&BTreeSet::new()));
+        // precompiles are warm from the start and not reported
+        assert!(!executed.accessed.contains_key(&Address::from(1)));
+    }
+
+    #[test]
+    fn test_transact_does_not_record_access_unless_asked() {
+        let keypair = Random.generate().unwrap();
+        let contract = Address::from(0x100);
+        let t = TypedTransaction::Legacy(Transaction {
+            action: Action::Call(contract),
+            value: U256::zero(),
+            data: vec![],
+            gas: U256::from(100_000),
+            gas_price: U256::zero(),
+            nonce: U256::zero(),
+        })
+        .sign(keypair.secret(), None);
+
+        let mut state = get_temp_state();
+        state
+            .init_code(&contract, "60005450600160015500".from_hex().unwrap())
+            .unwrap();
+        let mut info = EnvInfo::default();
+        info.gas_limit = U256::from(100_000);
+        let machine = ::ethereum::new_yolo3_test_machine();
+        let schedule = machine.schedule(info.number);
+
+        let executed = {
+            let mut ex = Executive::new(&mut state, &info, &machine, &schedule);
+            let opts = TransactOptions::with_no_tracing();
+            ex.transact(&t, opts).unwrap()
+        };
+
+        assert_eq!(executed.exception, None);
+        assert!(executed.accessed.is_empty());
+    }
+
     evm_test! {test_transact_invalid_nonce: test_transact_invalid_nonce_int}
     fn test_transact_invalid_nonce(factory: Factory) {
         let keypair = Random.generate().unwrap();
@@ -252,6 +252,7 @@ pub struct Miner {
     accounts: Arc<dyn LocalAccounts>,
     io_channel: RwLock<Option<IoChannel<ClientIoMessage>>>,
     service_transaction_checker: Option<ServiceTransactionChecker>,
+    queue_pressure_threshold: RwLock<Option<usize>>,
 }
 
 impl Miner {
@@ -308,6 +309,7 @@ impl Miner {
             } else {
                 Some(ServiceTransactionChecker::default())
             },
+            queue_pressure_threshold: RwLock::new(None),
         }
     }
 
@@ -975,6 +977,18 @@ impl miner::MinerService for Miner {
         }
     }
 
+    fn set_queue_lifetime(&self, lifetime: Option<Duration>) {
+        self.transaction_queue.set_lifetime(lifetime);
+    }
+
+    fn set_queue_pressure_threshold(&self, threshold: Option<usize>) {
+        *self.queue_pressure_threshold.write() = threshold;
+    }
+
+    fn queue_pressure_threshold(&self) -> Option<usize> {
+        *self.queue_pressure_threshold.read()
+    }
+
     fn import_external_transactions<C: miner::BlockChainClient>(
         &self,
         chain: &C,
@@ -1064,6 +1078,10 @@ impl miner::MinerService for Miner {
         self.transaction_queue.all_transactions()
     }
 
+    fn queued_transactions_from_sender(&self, sender: &Address) -> Vec<Arc<VerifiedTransaction>> {
+        self.transaction_queue.transactions_from_sender(sender)
+    }
+
     fn queued_transaction_hashes(&self) -> Vec<H256> {
         self.transaction_queue.all_transaction_hashes()
     }
@@ -1372,6 +1390,14 @@ impl miner::MinerService for Miner {
         // t_nb 10.2 Then import all transactions from retracted blocks (retracted means from side chain).
         let client = self.pool_client(chain);
         {
+            // Local transactions mined in retracted blocks are re-imported as local,
+            // so that they're prioritized again and tracked as pending.
+            let local_transactions = if retracted.is_empty() {
+                Default::default()
+            } else {
+                self.transaction_queue.local_transactions()
+            };
+
             retracted
 				.par_iter()
 				.for_each(|hash| {
@@ -1379,7 +1405,12 @@ impl miner::MinerService for Miner {
 						.expect("Client is sending message after commit to db and inserting to chain; the block is available; qed");
 					let txs = block.transactions()
 						.into_iter()
-						.map(pool::verifier::Transaction::Retracted)
+						.map(|tx| match local_transactions.get(&tx.hash()) {
+							Some(&pool::local_transactions::Status::Mined(ref local)) => {
+								pool::verifier::Transaction::Local(local.pending().clone())
+							},
+							_ => pool::verifier::Transaction::Retracted(tx),
+						})
                         .collect();
                     // t_nb 10.2
 					let _ = self.transaction_queue.import(
@@ -1504,13 +1535,15 @@ mod tests {
     use accounts::AccountProvider;
     use ethkey::{Generator, Random};
     use hash::keccak;
+    use rlp::RlpStream;
     use rustc_hex::FromHex;
     use types::BlockNumber;
 
-    use client::{ChainInfo, EachBlockWith, ImportSealedBlock, TestBlockChainClient};
+    use client::{ChainInfo, EachBlockWith, ImportBlock, ImportSealedBlock, TestBlockChainClient};
     use miner::{MinerService, PendingOrdering};
     use test_helpers::{generate_dummy_client, generate_dummy_client_with_spec};
     use types::transaction::{Transaction, TypedTransaction};
+    use verification::queue::kind::blocks::Unverified;
 
     #[test]
     fn should_prepare_block_to_seal() {
@@ -1674,6 +1707,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_requeue_local_transactions_from_retracted_blocks() {
+        // given
+        let client = TestBlockChainClient::default();
+        let miner = miner();
+        let transaction = transaction();
+        let hash = transaction.hash();
+        let sender = transaction.sender();
+        miner
+            .transaction_queue
+            .set_in_chain_checker(move |h| *h == hash);
+        let block = |transactions: Vec<SignedTransaction>, timestamp| {
+            let mut header = Header::new();
+            header.set_parent_hash(client.chain_info().genesis_hash);
+            header.set_number(1);
+            header.set_gas_limit(U256::from(1_000_000));
+            header.set_timestamp(timestamp);
+            let mut rlp = RlpStream::new_list(3);
+            rlp.append(&header);
+            rlp.begin_list(transactions.len());
+            for transaction in transactions {
+                transaction.rlp_append(&mut rlp);
+            }
+            rlp.append_raw(&::rlp::EMPTY_LIST_RLP, 1);
+            client
+                .import_block(Unverified::from_rlp(rlp.out()).unwrap())
+                .unwrap()
+        };
+        miner
+            .import_own_transaction(&client, PendingTransaction::new(transaction.clone(), None))
+            .unwrap();
+
+        // when the transaction is mined
+        let mined = block(vec![transaction], 0);
+        client.set_nonce(sender, 1.into());
+        miner.chain_new_blocks(&client, &[mined], &[], &[mined], &[], false);
+        assert!(miner.queued_transactions().is_empty());
+
+        // and its block is retracted
+        let sibling = block(vec![], 1);
+        client.set_nonce(sender, 0.into());
+        miner.chain_new_blocks(&client, &[sibling], &[], &[sibling], &[mined], false);
+
+        // then
+        assert_eq!(miner.queued_transactions().len(), 1);
+        match miner.local_transactions().get(&hash) {
+            Some(&pool::local_transactions::Status::Pending(_)) => {}
+            status => panic!("Expected pending local transaction, got {:?}", status),
+        }
+    }
+
     #[test]
     fn should_import_external_transaction() {
         // given
@@ -31,6 +31,7 @@ pub use ethcore_miner::{local_accounts::LocalAccounts, pool::PendingOrdering};
 use std::{
     collections::{BTreeMap, BTreeSet},
     sync::Arc,
+    time::Duration,
 };
 
 use bytes::Bytes;
@@ -226,6 +227,9 @@ pub trait MinerService: Send + Sync {
     /// Get a list of all transactions in the pool (some of them might not be ready for inclusion yet).
     fn queued_transactions(&self) -> Vec<Arc<VerifiedTransaction>>;
 
+    /// Get a list of all transactions in the pool sent from given address, ordered by nonce.
+    fn queued_transactions_from_sender(&self, sender: &Address) -> Vec<Arc<VerifiedTransaction>>;
+
     /// Get a list of all transaction hashes in the pool (some of them might not be ready for inclusion yet).
     fn queued_transaction_hashes(&self) -> Vec<H256>;
 
@@ -248,4 +252,35 @@ pub trait MinerService: Send + Sync {
     /// Set a new minimum gas limit.
     /// Will not work if dynamic gas calibration is set.
     fn set_minimal_gas_price(&self, gas_price: U256) -> Result<bool, &str>;
+
+    /// Set the maximal time a future transaction may wait in the queue before being dropped.
+    /// `None` keeps queued transactions until they are culled for other reasons.
+    fn set_queue_lifetime(&self, lifetime: Option<Duration>);
+
+    /// Set the queue occupancy (in percent of its capacity) from which the queue is reported
+    /// to be under pressure. `None` disables the signal.
+    fn set_queue_pressure_threshold(&self, threshold: Option<usize>);
+
+    /// Get the queue occupancy (in percent) from which the queue is reported to be under pressure.
+    fn queue_pressure_threshold(&self) -> Option<usize>;
+
+    /// Current queue occupancy in percent of its capacity.
+    fn queue_occupancy(&self) -> usize {
+        let status = self.queue_status();
+        match status.limits.max_count {
+            0 => 100,
+            max => status.status.transaction_count * 100 / max,
+        }
+    }
+
+    /// Returns current queue occupancy if it reached the pressure threshold.
+    fn queue_pressure(&self) -> Option<usize> {
+        let threshold = self.queue_pressure_threshold()?;
+        let occupancy = self.queue_occupancy();
+        if occupancy >= threshold {
+            Some(occupancy)
+        } else {
+            None
+        }
+    }
 }
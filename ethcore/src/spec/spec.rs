@@ -53,6 +53,32 @@ pub use ethash::OptimizeFor;
 
 const MAX_TRANSACTION_SIZE: usize = 300 * 1024;
 
+/// Names of the forks whose rules are implemented by this node.
+pub const SUPPORTED_FORKS: &[&str] = &[
+    "frontier",
+    "homestead",
+    "tangerineWhistle",
+    "spuriousDragon",
+    "byzantium",
+    "constantinople",
+    "petersburg",
+    "istanbul",
+    "berlin",
+];
+
+/// Ability of the node to follow the consensus rules of the chain.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ConsensusCapability {
+    /// The chain spec doesn't announce its forks, so capability can't be determined.
+    Unknown,
+    /// All announced forks are supported.
+    Capable,
+    /// Capable up to (but not including) the given block, where an unsupported fork activates.
+    CapableUntil(BlockNumber),
+    /// An unsupported fork has been active since the given block.
+    IncapableSince(BlockNumber),
+}
+
 // helper for formatting errors.
 fn fmt_err<F: ::std::fmt::Display>(f: F) -> String {
     format!("Spec json is invalid: {}", f)
@@ -169,9 +195,32 @@ pub struct CommonParams {
     pub transaction_permission_contract_transition: BlockNumber,
     /// Maximum size of transaction's RLP payload
     pub max_transaction_size: usize,
+    /// Named forks announced by the chain spec, mapped to their activation block.
+    pub scheduled_forks: BTreeMap<String, BlockNumber>,
 }
 
 impl CommonParams {
+    /// Reports whether this node is able to follow the consensus rules announced by the spec
+    /// at the given block.
+    pub fn consensus_capability(&self, block_number: BlockNumber) -> ConsensusCapability {
+        if self.scheduled_forks.is_empty() {
+            return ConsensusCapability::Unknown;
+        }
+
+        let unsupported = self
+            .scheduled_forks
+            .iter()
+            .filter(|&(name, _)| !SUPPORTED_FORKS.contains(&name.as_str()))
+            .map(|(_, block)| *block)
+            .min();
+
+        match unsupported {
+            None => ConsensusCapability::Capable,
+            Some(block) if block_number >= block => ConsensusCapability::IncapableSince(block),
+            Some(block) => ConsensusCapability::CapableUntil(block),
+        }
+    }
+
     /// Schedule for an EVM in the post-EIP-150-era of the Ethereum main net.
     pub fn schedule(&self, block_number: u64) -> ::vm::Schedule {
         if block_number < self.eip150_transition {
@@ -405,6 +454,12 @@ impl From<ethjson::spec::Params> for CommonParams {
             kip6_transition: p
                 .kip6_transition
                 .map_or_else(BlockNumber::max_value, Into::into),
+            scheduled_forks: p
+                .scheduled_forks
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, block)| (name, block.into()))
+                .collect(),
         }
     }
 }
@@ -582,3 +582,72 @@ fn import_export_binary() {
     assert!(client.block_header(BlockId::Number(17)).is_some());
     assert!(client.block_header(BlockId::Number(16)).is_some());
 }
+
+#[test]
+fn estimate_gas_is_bounded() {
+    use client::Call;
+    use executed::{CallError, ExecutionError};
+
+    let spec = Spec::new_test();
+    let mut config = ClientConfig::default();
+    config.estimate_gas_max_probes = 2;
+    let client = Client::new(
+        config,
+        &spec,
+        test_helpers::new_db(),
+        Arc::new(Miner::new_for_tests(&spec, None)),
+        IoChannel::disconnected(),
+    )
+    .unwrap();
+
+    let failing = Address::from(0x10);
+    let storing = Address::from(0x20);
+    let mut b = client
+        .prepare_open_block(
+            Address::default(),
+            (3141562.into(), 31415620.into()),
+            vec![],
+        )
+        .unwrap();
+    {
+        let state = b.block_mut().state_mut();
+        // INVALID
+        state.init_code(&failing, vec![0xfe]).unwrap();
+        // PUSH1 1 PUSH1 0 SSTORE
+        state
+            .init_code(&storing, vec![0x60, 0x01, 0x60, 0x00, 0x55])
+            .unwrap();
+        state.commit().unwrap();
+    }
+    let b = b
+        .close_and_lock()
+        .unwrap()
+        .seal(&*spec.engine, vec![])
+        .unwrap();
+    client.import_sealed_block(b).unwrap();
+
+    let call = |to| {
+        TypedTransaction::Legacy(Transaction {
+            nonce: 0.into(),
+            gas_price: 0.into(),
+            gas: 0.into(),
+            action: Action::Call(to),
+            value: 0.into(),
+            data: Vec::new(),
+        })
+        .fake_sign(Address::default())
+    };
+    let (state, header) = client.latest_state_and_header();
+
+    // never succeeds, rejected without searching
+    match client.estimate_gas(&call(failing), &state, &header) {
+        Err(CallError::Exceptional(_)) => {}
+        other => panic!("Unexpected estimate: {:?}", other),
+    }
+
+    // succeeds, but the search runs out of probes long before converging
+    match client.estimate_gas(&call(storing), &state, &header) {
+        Err(CallError::Execution(ExecutionError::Internal(_))) => {}
+        other => panic!("Unexpected estimate: {:?}", other),
+    }
+}
@@ -25,4 +25,6 @@ pub struct CallAnalytics {
     pub vm_tracing: bool,
     /// Make a diff.
     pub state_diffing: bool,
+    /// Record the accounts and storage keys accessed.
+    pub access_recording: bool,
 }
@@ -77,8 +77,8 @@ impl Filter {
         };
 
         self.topics.iter().fold(blooms, |bs, topic| match *topic {
-            None => bs,
-            Some(ref topics) => bs
+            // An empty list of alternatives matches any topic, same as `None`.
+            Some(ref topics) if !topics.is_empty() => bs
                 .into_iter()
                 .flat_map(|bloom| {
                     topics
@@ -91,6 +91,7 @@ impl Filter {
                         .collect::<Vec<Bloom>>()
                 })
                 .collect(),
+            _ => bs,
         })
     }
 
@@ -139,6 +140,21 @@ mod tests {
         assert!(possibilities[0].is_zero())
     }
 
+    #[test]
+    fn test_bloom_possibilities_empty_topics() {
+        let filter = Filter {
+            from_block: BlockId::Earliest,
+            to_block: BlockId::Latest,
+            address: None,
+            topics: vec![Some(vec![]), None, None, None],
+            limit: None,
+        };
+
+        let possibilities = filter.bloom_possibilities();
+        assert_eq!(possibilities.len(), 1);
+        assert!(possibilities[0].is_zero())
+    }
+
     // block 399849
     #[test]
     fn test_bloom_possibilities_single_address_and_topic() {
@@ -161,6 +161,18 @@ impl AccessList {
             journal.addresses.insert(address, self.id);
         }
     }
+    /// Returns all addresses in the list
+    pub fn addresses(&self) -> Vec<Address> {
+        let journal = self.journal.as_ref().borrow();
+        journal.addresses.keys().cloned().collect()
+    }
+
+    /// Returns all storage keys in the list, along with the address they belong to
+    pub fn storage_keys(&self) -> Vec<(Address, H256)> {
+        let journal = self.journal.as_ref().borrow();
+        journal.storage_keys.keys().cloned().collect()
+    }
+
     /// Removes all changes in journal
     pub fn rollback(&self) {
         let mut journal = self.journal.as_ref().borrow_mut();
@@ -257,4 +269,22 @@ mod tests {
             access_list.contains_storage_key(&Address::from(6), &H256::from(7))
         );
     }
+
+    #[test]
+    fn accesslist_lists_registered_entries() {
+        let mut access_list = AccessList::default();
+        access_list.enable();
+        access_list.insert_address(Address::from(1));
+        access_list.insert_storage_key(Address::from(2), H256::from(3));
+
+        let mut access_list_call = access_list.clone();
+        access_list_call.insert_storage_key(Address::from(4), H256::from(5));
+        access_list_call.rollback();
+
+        assert_eq!(access_list.addresses(), vec![Address::from(1)]);
+        assert_eq!(
+            access_list.storage_keys(),
+            vec![(Address::from(2), H256::from(3))]
+        );
+    }
 }
@@ -17,6 +17,8 @@
 //! Spec params deserialization.
 
 use bytes::Bytes;
+use std::collections::BTreeMap;
+
 use hash::{Address, H256};
 use uint::{self, Uint};
 
@@ -142,6 +144,8 @@ pub struct Params {
     pub kip4_transition: Option<Uint>,
     /// KIP6 activiation block height.
     pub kip6_transition: Option<Uint>,
+    /// Named forks announced by the chain, mapped to their activation block.
+    pub scheduled_forks: Option<BTreeMap<String, Uint>>,
 }
 
 #[cfg(test)]
@@ -24,6 +24,7 @@ use std::{
         atomic::{self, AtomicUsize},
         Arc,
     },
+    time::{Duration, Instant},
 };
 
 use ethereum_types::{Address, H256, U256};
@@ -210,6 +211,8 @@ pub struct TransactionQueue {
     options: RwLock<verifier::Options>,
     cached_pending: RwLock<CachedPending>,
     recently_rejected: RecentlyRejected,
+    lifetime: RwLock<Option<Duration>>,
+    imported_at: RwLock<HashMap<H256, Instant>>,
 }
 
 impl TransactionQueue {
@@ -233,6 +236,8 @@ impl TransactionQueue {
                 MIN_REJECTED_CACHE_SIZE,
                 max_count / 4,
             )),
+            lifetime: RwLock::new(None),
+            imported_at: Default::default(),
         }
     }
 
@@ -243,6 +248,13 @@ impl TransactionQueue {
         *self.options.write() = options;
     }
 
+    /// Sets the maximal time a future transaction is allowed to stay in the queue.
+    ///
+    /// Expired transactions are dropped during the next `cull`. `None` disables the limit.
+    pub fn set_lifetime(&self, lifetime: Option<Duration>) {
+        *self.lifetime.write() = lifetime;
+    }
+
     /// Sets the in-chain transaction checker for pool listener.
     pub fn set_in_chain_checker<F>(&self, f: F)
     where
@@ -310,7 +322,10 @@ impl TransactionQueue {
 					});
 
 				match imported {
-					Ok(_) => Ok(()),
+					Ok(_) => {
+						self.imported_at.write().insert(hash, Instant::now());
+						Ok(())
+					},
 					Err(err) => {
 						self.recently_rejected.insert(hash, &err);
 						Err(err)
@@ -335,6 +350,18 @@ impl TransactionQueue {
         self.pool.read().unordered_pending(ready).collect()
     }
 
+    /// Returns all transactions in the queue sent from given address, ordered by nonce.
+    pub fn transactions_from_sender(
+        &self,
+        sender: &Address,
+    ) -> Vec<Arc<pool::VerifiedTransaction>> {
+        let ready = |_tx: &pool::VerifiedTransaction| txpool::Readiness::Ready;
+        self.pool
+            .read()
+            .pending_from_sender(ready, sender)
+            .collect()
+    }
+
     /// Returns all transaction hashes in the queue without explicit ordering.
     pub fn all_transaction_hashes(&self) -> Vec<H256> {
         let ready = |_tx: &pool::VerifiedTransaction| txpool::Readiness::Ready;
@@ -503,6 +530,45 @@ impl TransactionQueue {
             removed += self.pool.write().cull(Some(chunk), state_readiness);
         }
         debug!(target: "txqueue", "Removed {} stalled transactions. {}", removed, self.status());
+
+        self.cull_expired(client);
+    }
+
+    /// Drops future transactions that have been waiting in the queue for longer than the configured lifetime.
+    fn cull_expired<C: client::NonceClient>(&self, client: C) {
+        // Forget transactions that already left the pool, whether or not a lifetime is set.
+        {
+            let pool = self.pool.read();
+            self.imported_at
+                .write()
+                .retain(|hash, _| pool.find(hash).is_some());
+        }
+
+        let lifetime = match *self.lifetime.read() {
+            Some(lifetime) => lifetime,
+            None => return,
+        };
+
+        let pending = self.pending_hashes(|address| Some(client.account_nonce(address)));
+        let expired: Vec<H256> = {
+            self.imported_at
+                .read()
+                .iter()
+                .filter(|&(hash, at)| at.elapsed() > lifetime && !pending.contains(hash))
+                .map(|(hash, _)| *hash)
+                .collect()
+        };
+
+        if expired.is_empty() {
+            return;
+        }
+
+        let removed = self
+            .remove(expired.iter(), false)
+            .into_iter()
+            .filter(Option::is_some)
+            .count();
+        debug!(target: "txqueue", "Removed {} expired future transactions.", removed);
     }
 
     /// Returns next valid nonce for given sender
@@ -560,6 +626,7 @@ impl TransactionQueue {
     /// Clear the entire pool.
     pub fn clear(&self) {
         self.pool.write().clear();
+        self.imported_at.write().clear();
     }
 
     /// Penalize given senders.
@@ -626,6 +693,12 @@ impl TransactionQueue {
     pub fn is_pending_cached(&self) -> bool {
         self.cached_pending.read().pending.is_some()
     }
+
+    /// Number of transactions with a recorded import time.
+    #[cfg(test)]
+    pub fn tracked_import_times(&self) -> usize {
+        self.imported_at.read().len()
+    }
 }
 
 fn convert_error<H: fmt::Debug + fmt::LowerHex>(err: txpool::Error<H>) -> transaction::Error {
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
 
+use std::{thread, time::Duration};
+
 use ethereum_types::U256;
 use txpool;
 use types::transaction::{self, PendingTransaction};
@@ -287,6 +289,30 @@ fn should_import_txs_from_same_sender() {
     assert_eq!(top.len(), 2);
 }
 
+#[test]
+fn should_return_transactions_from_given_sender() {
+    // given
+    let txq = new_queue();
+    let txs = Tx::default().signed_pair();
+    let (hash, hash2) = txs.hash();
+    let sender = txs.0.sender();
+    let other = Tx::default().signed();
+    let other_sender = other.sender();
+    txq.import(TestClient::new(), txs.local().into_vec());
+    txq.import(TestClient::new(), vec![other.local()]);
+
+    // when
+    let from_sender = txq.transactions_from_sender(&sender);
+    let from_other = txq.transactions_from_sender(&other_sender);
+
+    // then
+    assert_eq!(from_sender.len(), 2);
+    assert_eq!(from_sender[0].hash, hash);
+    assert_eq!(from_sender[1].hash, hash2);
+    assert_eq!(from_other.len(), 1);
+    assert!(txq.transactions_from_sender(&Default::default()).is_empty());
+}
+
 #[test]
 fn should_prioritize_local_transactions_within_same_nonce_height() {
     // given
@@ -527,6 +553,44 @@ fn should_clear_queue() {
     assert_eq!(txq.status().status.transaction_count, 0);
 }
 
+#[test]
+fn should_forget_import_times_of_culled_transactions_without_lifetime() {
+    // given
+    let txq = new_queue();
+    let (tx1, tx2) = Tx::default().signed_pair();
+    txq.import(TestClient::new(), vec![tx1, tx2].local());
+    assert_eq!(txq.tracked_import_times(), 2);
+
+    // when
+    txq.cull(TestClient::new().with_nonce(125));
+
+    // then
+    assert_eq!(txq.status().status.transaction_count, 0);
+    assert_eq!(txq.tracked_import_times(), 0);
+}
+
+#[test]
+fn should_drop_expired_future_transactions() {
+    // given
+    let txq = new_queue();
+    txq.set_lifetime(Some(Duration::from_millis(10)));
+    let (tx1, _, tx3) = Tx::default().signed_triple();
+    let hash = tx1.hash();
+    txq.import(TestClient::new(), vec![tx1, tx3].local());
+    assert_eq!(txq.status().status.transaction_count, 2);
+
+    // when
+    thread::sleep(Duration::from_millis(20));
+    txq.cull(TestClient::new());
+
+    // then
+    assert_eq!(txq.status().status.transaction_count, 1);
+    let top = txq.pending(TestClient::new(), PendingSettings::all_prioritized(0, 0));
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].hash, hash);
+    assert_eq!(txq.tracked_import_times(), 1);
+}
+
 #[test]
 fn should_prefer_current_transactions_when_hitting_the_limit() {
     // given
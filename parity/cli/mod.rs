@@ -437,6 +437,38 @@ usage! {
             "--poll-lifetime=[S]",
             "Set the RPC filter lifetime to S seconds. The filter has to be polled at least every S seconds , otherwise it is removed.",
 
+            ARG arg_jsonrpc_logs_cache_size: (usize) = 0usize, or |c: &Config| c.rpc.as_ref()?.logs_cache_size,
+            "--jsonrpc-logs-cache-size=[QUERIES]",
+            "Number of eth_getLogs responses to cache for block ranges that are already final. Set to 0 to disable the cache.",
+
+            ARG arg_jsonrpc_logs_cache_confirmations: (u64) = 64u64, or |c: &Config| c.rpc.as_ref()?.logs_cache_confirmations,
+            "--jsonrpc-logs-cache-confirmations=[BLOCKS]",
+            "Number of confirmations after which a block is considered final and eth_getLogs responses covering it may be cached. This is a heuristic, not consensus finality: cached responses are tied to the hash of the range's last block, so after a deeper reorg they are no longer served.",
+
+            ARG arg_jsonrpc_logs_bloom_index_size: (usize) = 0usize, or |c: &Config| c.rpc.as_ref()?.logs_bloom_index_size,
+            "--jsonrpc-logs-bloom-index-size=[BLOCKS]",
+            "Number of latest blocks whose blooms are kept in memory to skip blocks without matching logs in eth_getLogs. Set to 0 to disable the index.",
+
+            FLAG flag_jsonrpc_receipt_revert_reason: (bool) = false, or |c: &Config| c.rpc.as_ref()?.receipt_revert_reason.clone(),
+            "--jsonrpc-receipt-revert-reason",
+            "Re-execute failed transactions to include the revert reason in eth_getTransactionReceipt responses.",
+
+            ARG arg_jsonrpc_logs_max_blocks: (u64) = 0u64, or |c: &Config| c.rpc.as_ref()?.logs_max_blocks,
+            "--jsonrpc-logs-max-blocks=[BLOCKS]",
+            "Maximal number of blocks a single eth_getLogs query may span. Set to 0 for no limit.",
+
+            ARG arg_jsonrpc_logs_max_results: (usize) = 0usize, or |c: &Config| c.rpc.as_ref()?.logs_max_results,
+            "--jsonrpc-logs-max-results=[LOGS]",
+            "Maximal number of logs a single eth_getLogs query may return. Set to 0 for no limit.",
+
+            ARG arg_jsonrpc_estimate_gas_cap: (Option<String>) = None, or |c: &Config| c.rpc.as_ref()?.estimate_gas_cap.clone(),
+            "--jsonrpc-estimate-gas-cap=[GAS]",
+            "Maximal gas eth_estimateGas is allowed to probe with. Defaults to the block gas limit.",
+
+            ARG arg_jsonrpc_estimate_gas_max_probes: (usize) = 64usize, or |c: &Config| c.rpc.as_ref()?.estimate_gas_max_probes,
+            "--jsonrpc-estimate-gas-max-probes=[PROBES]",
+            "Maximal number of executions eth_estimateGas runs while searching for the lowest gas.",
+
         ["API and Console Options – WebSockets"]
             FLAG flag_no_ws: (bool) = false, or |c: &Config| c.websockets.as_ref()?.disable.clone(),
             "--no-ws",
@@ -895,6 +927,14 @@ struct Rpc {
     experimental_rpcs: Option<bool>,
     poll_lifetime: Option<u32>,
     allow_missing_blocks: Option<bool>,
+    logs_max_blocks: Option<u64>,
+    logs_max_results: Option<usize>,
+    estimate_gas_cap: Option<String>,
+    estimate_gas_max_probes: Option<usize>,
+    receipt_revert_reason: Option<bool>,
+    logs_bloom_index_size: Option<usize>,
+    logs_cache_size: Option<usize>,
+    logs_cache_confirmations: Option<u64>,
 }
 
 #[derive(Default, Debug, PartialEq, Deserialize)]
@@ -1319,6 +1359,14 @@ mod tests {
                 arg_jsonrpc_max_payload: None,
                 arg_poll_lifetime: 60u32,
                 flag_jsonrpc_allow_missing_blocks: false,
+                arg_jsonrpc_logs_max_blocks: 0u64,
+                arg_jsonrpc_logs_max_results: 0usize,
+                arg_jsonrpc_estimate_gas_cap: None,
+                arg_jsonrpc_estimate_gas_max_probes: 64usize,
+                flag_jsonrpc_receipt_revert_reason: false,
+                arg_jsonrpc_logs_bloom_index_size: 0usize,
+                arg_jsonrpc_logs_cache_size: 0usize,
+                arg_jsonrpc_logs_cache_confirmations: 64u64,
 
                 // WS
                 flag_no_ws: false,
@@ -1533,7 +1581,15 @@ mod tests {
                     keep_alive: None,
                     experimental_rpcs: None,
                     poll_lifetime: None,
-                    allow_missing_blocks: None
+                    allow_missing_blocks: None,
+                    logs_cache_size: None,
+                    logs_cache_confirmations: None,
+                    logs_bloom_index_size: None,
+                    receipt_revert_reason: None,
+                    logs_max_blocks: None,
+                    logs_max_results: None,
+                    estimate_gas_cap: None,
+                    estimate_gas_max_probes: None,
                 }),
                 ipc: Some(Ipc {
                     disable: None,
@@ -424,6 +424,17 @@ impl Configuration {
                 verifier_settings: verifier_settings,
                 no_persistent_txqueue: self.args.flag_no_persistent_txqueue,
                 max_round_blocks_to_import: self.args.arg_max_round_blocks_to_import,
+                logs_cache_size: self.args.arg_jsonrpc_logs_cache_size,
+                logs_cache_confirmations: self.args.arg_jsonrpc_logs_cache_confirmations,
+                logs_bloom_index_size: self.args.arg_jsonrpc_logs_bloom_index_size,
+                receipt_revert_reason: self.args.flag_jsonrpc_receipt_revert_reason,
+                logs_max_blocks: self.args.arg_jsonrpc_logs_max_blocks,
+                logs_max_results: self.args.arg_jsonrpc_logs_max_results,
+                estimate_gas_cap: match self.args.arg_jsonrpc_estimate_gas_cap {
+                    Some(ref gas) => Some(to_u256(gas)?),
+                    None => None,
+                },
+                estimate_gas_max_probes: self.args.arg_jsonrpc_estimate_gas_max_probes,
                 metrics_conf,
             };
             Cmd::Run(run_cmd)
@@ -980,6 +991,7 @@ impl Configuration {
 
     fn network_settings(&self) -> Result<NetworkSettings, String> {
         let http_conf = self.http_config()?;
+        let ws_conf = self.ws_config()?;
         let net_addresses = self.net_addresses()?;
         Ok(NetworkSettings {
             name: self.args.arg_identity.clone(),
@@ -989,6 +1001,8 @@ impl Configuration {
             rpc_enabled: http_conf.enabled,
             rpc_interface: http_conf.interface,
             rpc_port: http_conf.port,
+            ws_enabled: ws_conf.enabled,
+            ws_port: ws_conf.port,
         })
     }
 
@@ -1553,6 +1567,14 @@ mod tests {
             verifier_settings: Default::default(),
             no_persistent_txqueue: false,
             max_round_blocks_to_import: 1,
+            logs_cache_size: 0,
+            logs_cache_confirmations: 64,
+            logs_bloom_index_size: 0,
+            receipt_revert_reason: false,
+            logs_max_blocks: 0,
+            logs_max_results: 0,
+            estimate_gas_cap: None,
+            estimate_gas_max_probes: 64,
             metrics_conf: MetricsConfiguration::default(),
         };
         expected.secretstore_conf.enabled = cfg!(feature = "secretstore");
@@ -1613,6 +1635,8 @@ mod tests {
                 rpc_enabled: true,
                 rpc_interface: "127.0.0.1".to_owned(),
                 rpc_port: 8545,
+                ws_enabled: true,
+                ws_port: 8546,
             })
         );
     }
@@ -1719,6 +1743,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_parse_estimate_gas_limits() {
+        let args = vec![
+            "openethereum",
+            "--jsonrpc-estimate-gas-cap=0x100000",
+            "--jsonrpc-estimate-gas-max-probes=8",
+        ];
+        let conf = parse(&args);
+        match conf.into_command().unwrap().cmd {
+            Cmd::Run(c) => {
+                assert_eq!(c.estimate_gas_cap, Some(U256::from(0x100000)));
+                assert_eq!(c.estimate_gas_max_probes, 8);
+            }
+            _ => panic!("Should be Cmd::Run"),
+        }
+    }
+
     #[test]
     fn test_non_standard_ports_preset() {
         let args = vec!["openethereum", "--config", "non-standard-ports"];
@@ -1819,6 +1860,7 @@ mod tests {
         assert_eq!(conf0.network_settings().unwrap().rpc_port, 8546);
         assert_eq!(conf0.http_config().unwrap().port, 8546);
         assert_eq!(conf0.ws_config().unwrap().port, 8547);
+        assert_eq!(conf0.network_settings().unwrap().ws_port, 8547);
         assert_eq!(conf0.secretstore_config().unwrap().port, 8084);
         assert_eq!(conf0.secretstore_config().unwrap().http_port, 8083);
         assert_eq!(conf0.stratum_options().unwrap().unwrap().port, 8009);
@@ -1828,6 +1870,7 @@ mod tests {
         assert_eq!(conf1.network_settings().unwrap().rpc_port, 8545);
         assert_eq!(conf1.http_config().unwrap().port, 8545);
         assert_eq!(conf1.ws_config().unwrap().port, 8547);
+        assert_eq!(conf1.network_settings().unwrap().ws_port, 8547);
         assert_eq!(conf1.secretstore_config().unwrap().port, 8084);
         assert_eq!(conf1.secretstore_config().unwrap().http_port, 8083);
     }
@@ -25,9 +25,10 @@ use fetch::Client as FetchClient;
 use jsonrpc_core::{self as core, MetaIoHandler};
 use miner::external::ExternalMiner;
 use parity_rpc::{
+    dapps::RecentDapps,
     dispatch::FullDispatcher,
     informant::{ActivityNotifier, ClientNotifier},
-    Host, Metadata, NetworkSettings,
+    Host, Metadata, NetworkSettings, NodeName,
 };
 use parity_runtime::Executor;
 use parking_lot::Mutex;
@@ -191,6 +192,7 @@ pub struct FullDependencies {
     pub external_miner: Arc<ExternalMiner>,
     pub logger: Arc<RotatingLogger>,
     pub settings: Arc<NetworkSettings>,
+    pub node_name: Arc<NodeName>,
     pub net_service: Arc<dyn ManageNetwork>,
     pub experimental_rpcs: bool,
     pub ws_address: Option<Host>,
@@ -200,6 +202,13 @@ pub struct FullDependencies {
     pub poll_lifetime: u32,
     pub allow_missing_blocks: bool,
     pub no_ancient_blocks: bool,
+    pub logs_cache_size: usize,
+    pub logs_cache_confirmations: u64,
+    pub logs_bloom_index_size: usize,
+    pub receipt_revert_reason: bool,
+    pub logs_max_blocks: u64,
+    pub logs_max_results: usize,
+    pub recent_dapps: Arc<RecentDapps>,
 }
 
 impl FullDependencies {
@@ -231,7 +240,7 @@ impl FullDependencies {
                     handler.extend_with(DebugClient::new(self.client.clone()).to_delegate());
                 }
                 Api::Web3 => {
-                    handler.extend_with(Web3Client::default().to_delegate());
+                    handler.extend_with(Web3Client::new(&self.node_name).to_delegate());
                 }
                 Api::Net => {
                     handler.extend_with(NetClient::new(&self.sync).to_delegate());
@@ -249,8 +258,19 @@ impl FullDependencies {
                             allow_missing_blocks: self.allow_missing_blocks,
                             allow_experimental_rpcs: self.experimental_rpcs,
                             no_ancient_blocks: self.no_ancient_blocks,
+                            logs_cache_size: self.logs_cache_size,
+                            logs_cache_confirmations: self.logs_cache_confirmations,
+                            logs_bloom_index_size: self.logs_bloom_index_size,
+                            receipt_revert_reason: self.receipt_revert_reason,
+                            logs_max_blocks: self.logs_max_blocks,
+                            logs_max_results: self.logs_max_results,
                         },
                     );
+                    if self.logs_bloom_index_size > 0 {
+                        if let Some(h) = client.bloom_index_handler().upgrade() {
+                            self.client.add_notify(h);
+                        }
+                    }
                     handler.extend_with(client.to_delegate());
 
                     if !for_generic_pubsub {
@@ -284,6 +304,7 @@ impl FullDependencies {
                         if let Some(h) = client.handler().upgrade() {
                             self.client.add_notify(h);
                         }
+                        client.poll_sync_status(self.snapshot.clone(), self.sync.clone());
                         handler.extend_with(client.to_delegate());
                     }
                 }
@@ -322,6 +343,7 @@ impl FullDependencies {
                             self.net_service.clone(),
                             self.logger.clone(),
                             self.settings.clone(),
+                            self.node_name.clone(),
                             signer,
                             self.ws_address.clone(),
                             self.snapshot.clone().into(),
@@ -366,6 +388,10 @@ impl FullDependencies {
                             &self.client,
                             &self.miner,
                             &self.net_service,
+                            None,
+                            &self.recent_dapps,
+                            None,
+                            &self.node_name,
                             self.fetch.clone(),
                         )
                         .to_delegate(),
@@ -391,6 +417,7 @@ impl Dependencies for FullDependencies {
     fn activity_notifier(&self) -> ClientNotifier {
         ClientNotifier {
             client: self.client.clone(),
+            recent_dapps: self.recent_dapps.clone(),
         }
     }
 
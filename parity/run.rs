@@ -34,7 +34,7 @@ use ethcore::{
 };
 use ethcore_logger::{Config as LogConfig, RotatingLogger};
 use ethcore_service::ClientService;
-use ethereum_types::H256;
+use ethereum_types::{H256, U256};
 use helpers::{execute_upgrades, passwords_from_files, to_client_config};
 use informant::{FullNodeInformantData, Informant};
 use journaldb::Algorithm;
@@ -48,8 +48,8 @@ use params::{
     GasPricerConfig, MinerExtras, Pruning, SpecType, Switch,
 };
 use parity_rpc::{
-    informant, is_major_importing, FutureOutput, FutureResponse, FutureResult, Metadata,
-    NetworkSettings, Origin, PubSubSession,
+    dapps::RecentDapps, informant, is_major_importing, FutureOutput, FutureResponse, FutureResult,
+    Metadata, NetworkSettings, NodeName, Origin, PubSubSession,
 };
 use parity_runtime::Runtime;
 use parity_version::version;
@@ -111,6 +111,14 @@ pub struct RunCmd {
     pub verifier_settings: VerifierSettings,
     pub no_persistent_txqueue: bool,
     pub max_round_blocks_to_import: usize,
+    pub logs_cache_size: usize,
+    pub logs_cache_confirmations: u64,
+    pub logs_bloom_index_size: usize,
+    pub receipt_revert_reason: bool,
+    pub logs_max_blocks: u64,
+    pub logs_max_results: usize,
+    pub estimate_gas_cap: Option<U256>,
+    pub estimate_gas_max_probes: usize,
     pub metrics_conf: MetricsConfiguration,
 }
 
@@ -333,6 +341,8 @@ pub fn execute(cmd: RunCmd, logger: Arc<RotatingLogger>) -> Result<RunningClient
     client_config.queue.verifier_settings.bad_hashes = verification_bad_blocks(&cmd.spec);
     client_config.transaction_verification_queue_size = ::std::cmp::max(2048, txpool_size / 4);
     client_config.snapshot = cmd.snapshot_conf.clone();
+    client_config.estimate_gas_cap = cmd.estimate_gas_cap;
+    client_config.estimate_gas_max_probes = cmd.estimate_gas_max_probes;
 
     // set up bootnodes
     let mut net_conf = cmd.net_conf;
@@ -481,6 +491,7 @@ pub fn execute(cmd: RunCmd, logger: Arc<RotatingLogger>) -> Result<RunningClient
         external_miner: external_miner.clone(),
         logger: logger.clone(),
         settings: Arc::new(cmd.net_settings.clone()),
+        node_name: Arc::new(NodeName::new(cmd.net_settings.name.clone())),
         net_service: manage_network.clone(),
         experimental_rpcs: cmd.experimental_rpcs,
         ws_address: cmd.ws_conf.address(),
@@ -490,6 +501,13 @@ pub fn execute(cmd: RunCmd, logger: Arc<RotatingLogger>) -> Result<RunningClient
         poll_lifetime: cmd.poll_lifetime,
         allow_missing_blocks: cmd.allow_missing_blocks,
         no_ancient_blocks: !cmd.download_old_blocks,
+        logs_cache_size: cmd.logs_cache_size,
+        logs_cache_confirmations: cmd.logs_cache_confirmations,
+        logs_bloom_index_size: cmd.logs_bloom_index_size,
+        receipt_revert_reason: cmd.receipt_revert_reason,
+        logs_max_blocks: cmd.logs_max_blocks,
+        logs_max_results: cmd.logs_max_results,
+        recent_dapps: Arc::new(RecentDapps::default()),
     });
 
     let dependencies = rpc::Dependencies {
@@ -636,6 +654,7 @@ impl RunningClient {
         let metadata = Metadata {
             origin: Origin::CApi,
             session,
+            dapp_id: None,
         };
 
         match self.inner {
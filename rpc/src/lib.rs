@@ -131,9 +131,9 @@ pub use authcodes::{AuthCodes, TimeProvider};
 pub use http_common::HttpMetaExtractor;
 pub use v1::{
     block_import::{is_major_importing, is_major_importing_or_waiting},
-    dispatch,
+    dapps, dispatch,
     extractors::{RpcExtractor, WsDispatcher, WsExtractor, WsStats},
-    informant, signer, Metadata, NetworkSettings, Origin,
+    informant, signer, Metadata, NetworkSettings, NodeName, Origin,
 };
 
 use std::net::SocketAddr;
@@ -42,10 +42,13 @@ impl HttpMetaExtractor for RpcExtractor {
         Metadata {
             origin: Origin::Rpc(format!(
                 "{} / {}",
-                origin.unwrap_or_else(|| "unknown origin".to_string()),
+                origin
+                    .clone()
+                    .unwrap_or_else(|| "unknown origin".to_string()),
                 user_agent.unwrap_or_else(|| "unknown agent".to_string())
             )),
             session: None,
+            dapp_id: origin,
         }
     }
 }
@@ -55,6 +58,7 @@ impl ipc::MetaExtractor<Metadata> for RpcExtractor {
         Metadata {
             origin: Origin::Ipc(req.session_id.into()),
             session: Some(Arc::new(Session::new(req.sender.clone()))),
+            dapp_id: None,
         }
     }
 }
@@ -91,7 +95,11 @@ impl ws::MetaExtractor<Metadata> for WsExtractor {
             None => Origin::Ws { session: id.into() },
         };
         let session = Some(Arc::new(Session::new(req.sender())));
-        Metadata { origin, session }
+        Metadata {
+            origin,
+            session,
+            dapp_id: None,
+        }
     }
 }
 
@@ -264,6 +272,7 @@ mod tests {
         let meta1 = extractor.read_metadata(None, None);
         let meta2 = extractor.read_metadata(None, Some("http://openethereum.github.io".to_owned()));
         let meta3 = extractor.read_metadata(None, Some("http://openethereum.github.io".to_owned()));
+        let meta4 = extractor.read_metadata(Some("http://localhost:3000".to_owned()), None);
 
         // then
         assert_eq!(
@@ -278,5 +287,7 @@ mod tests {
             meta3.origin,
             Origin::Rpc("unknown origin / http://openethereum.github.io".into())
         );
+        assert_eq!(meta1.dapp_id, None);
+        assert_eq!(meta4.dapp_id, Some("http://localhost:3000".into()));
     }
 }
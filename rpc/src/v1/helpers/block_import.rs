@@ -16,8 +16,13 @@
 
 //! Block import analysis functions.
 
-use ethcore::client::BlockQueueInfo;
-use sync::SyncState;
+use ethcore::{
+    client::{BlockChainClient, BlockQueueInfo},
+    snapshot::{RestorationStatus, SnapshotService},
+};
+use ethereum_types::U256;
+use sync::{SyncProvider, SyncState};
+use v1::types::{SyncInfo, SyncStatus};
 
 /// Check if client is during major sync or during block import and allows defining whether 'waiting for peers' should
 /// be considered a syncing state.
@@ -40,6 +45,57 @@ pub fn is_major_importing(sync_state: Option<SyncState>, queue_info: BlockQueueI
     is_major_importing_or_waiting(sync_state, queue_info, true)
 }
 
+/// Returns the sync status reported by `eth_syncing`.
+pub fn sync_status<C, SN: ?Sized, S: ?Sized>(client: &C, snapshot: &SN, sync: &S) -> SyncStatus
+where
+    C: BlockChainClient,
+    SN: SnapshotService,
+    S: SyncProvider,
+{
+    let status = sync.status();
+    let snapshot_status = snapshot.restoration_status();
+
+    let (warping, warp_chunks_amount, warp_chunks_processed) = match snapshot_status {
+        RestorationStatus::Ongoing {
+            state_chunks,
+            block_chunks,
+            state_chunks_done,
+            block_chunks_done,
+            ..
+        } => (
+            true,
+            Some(block_chunks + state_chunks),
+            Some(block_chunks_done + state_chunks_done),
+        ),
+        _ => (false, None, None),
+    };
+
+    if warping || is_major_importing(Some(status.state), client.queue_info()) {
+        let chain_info = client.chain_info();
+        let current_block = U256::from(chain_info.best_block_number);
+        let highest_block = U256::from(
+            status
+                .highest_block_number
+                .unwrap_or(status.start_block_number),
+        );
+
+        let info = SyncInfo {
+            starting_block: status.start_block_number.into(),
+            current_block,
+            highest_block,
+            warp_chunks_amount: warp_chunks_amount
+                .map(|x| U256::from(x as u64))
+                .map(Into::into),
+            warp_chunks_processed: warp_chunks_processed
+                .map(|x| U256::from(x as u64))
+                .map(Into::into),
+        };
+        SyncStatus::Info(info)
+    } else {
+        SyncStatus::None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::is_major_importing;
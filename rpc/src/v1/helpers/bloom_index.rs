@@ -0,0 +1,212 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of OpenEthereum.
+
+// OpenEthereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// OpenEthereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! In-memory index of header blooms over the most recent blocks.
+
+use std::{collections::VecDeque, sync::Arc};
+
+use ethcore::client::{BlockChainClient, BlockId, ChainNotify, NewBlocks};
+use ethereum_types::{Bloom, H256};
+use parking_lot::Mutex;
+use types::BlockNumber;
+
+/// Bounded window of `(number, hash, bloom)` for the latest canonical blocks.
+///
+/// Used by `eth_getLogs` to pick candidate blocks without loading receipts
+/// of blocks that cannot contain matching logs.
+pub struct BloomIndex {
+    capacity: usize,
+    entries: Mutex<VecDeque<(BlockNumber, H256, Bloom)>>,
+}
+
+impl BloomIndex {
+    /// Creates an index covering at most `capacity` blocks.
+    pub fn new(capacity: usize) -> Self {
+        BloomIndex {
+            capacity,
+            entries: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Extends the index up to `best_block`.
+    ///
+    /// `header` returns hash and bloom of the canonical block with given number.
+    /// Entries which are no longer canonical are dropped first, so the index
+    /// follows reorganisations.
+    pub fn update<F>(&self, best_block: BlockNumber, header: F)
+    where
+        F: Fn(BlockNumber) -> Option<(H256, Bloom)>,
+    {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock();
+        while let Some(&(number, hash, _)) = entries.back() {
+            if number <= best_block && header(number).map(|(h, _)| h) == Some(hash) {
+                break;
+            }
+            entries.pop_back();
+        }
+
+        let first = (best_block + 1).saturating_sub(self.capacity as u64);
+        let next = match entries.back() {
+            Some(&(number, _, _)) if number + 1 >= first => number + 1,
+            _ => {
+                entries.clear();
+                first
+            }
+        };
+
+        for number in next..=best_block {
+            match header(number) {
+                Some((hash, bloom)) => entries.push_back((number, hash, bloom)),
+                None => break,
+            }
+        }
+
+        while entries.len() > self.capacity {
+            entries.pop_front();
+        }
+    }
+
+    /// Returns numbers of blocks within `from_block..=to_block` whose bloom
+    /// matches any of `possibilities`.
+    ///
+    /// Returns `None` if the range is not fully covered by the index.
+    pub fn candidates(
+        &self,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+        possibilities: &[Bloom],
+    ) -> Option<Vec<BlockNumber>> {
+        let entries = self.entries.lock();
+        let (first, last) = match (entries.front(), entries.back()) {
+            (Some(first), Some(last)) => (first.0, last.0),
+            _ => return None,
+        };
+        if from_block < first || to_block > last {
+            return None;
+        }
+
+        Some(
+            entries
+                .iter()
+                .filter(|&&(number, _, _)| number >= from_block && number <= to_block)
+                .filter(|&&(_, _, ref bloom)| {
+                    possibilities
+                        .iter()
+                        .any(|possibility| bloom.contains_bloom(possibility))
+                })
+                .map(|&(number, _, _)| number)
+                .collect(),
+        )
+    }
+}
+
+/// Keeps a `BloomIndex` in sync with the canonical chain of the client.
+///
+/// Registered with the client as a `ChainNotify` so the index is extended
+/// when blocks are imported instead of on every `eth_getLogs` call.
+pub struct BloomIndexHandler<C> {
+    client: Arc<C>,
+    index: BloomIndex,
+}
+
+impl<C: BlockChainClient> BloomIndexHandler<C> {
+    /// Creates a handler indexing at most `capacity` latest blocks of `client`.
+    pub fn new(client: Arc<C>, capacity: usize) -> Self {
+        BloomIndexHandler {
+            client,
+            index: BloomIndex::new(capacity),
+        }
+    }
+
+    /// Returns numbers of indexed blocks within `from_block..=to_block` whose
+    /// bloom matches any of `possibilities`, see `BloomIndex::candidates`.
+    pub fn candidates(
+        &self,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+        possibilities: &[Bloom],
+    ) -> Option<Vec<BlockNumber>> {
+        self.index.candidates(from_block, to_block, possibilities)
+    }
+}
+
+impl<C: BlockChainClient> ChainNotify for BloomIndexHandler<C> {
+    fn new_blocks(&self, new_blocks: NewBlocks) {
+        if new_blocks.has_more_blocks_to_import {
+            return;
+        }
+
+        let client = &self.client;
+        let best_block = client.chain_info().best_block_number;
+        self.index.update(best_block, |number| {
+            client
+                .block_header(BlockId::Number(number))
+                .map(|header| (header.hash(), header.log_bloom()))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BloomIndex;
+    use ethereum_types::{Bloom, BloomInput, H256};
+
+    fn bloom_of(value: u8) -> Bloom {
+        Bloom::from(BloomInput::Raw(&[value]))
+    }
+
+    #[test]
+    fn should_return_only_matching_blocks() {
+        let index = BloomIndex::new(8);
+        index.update(4, |n| Some((H256::from(n), bloom_of(n as u8))));
+
+        assert_eq!(index.candidates(1, 4, &[bloom_of(3)]), Some(vec![3]));
+        assert_eq!(index.candidates(1, 2, &[bloom_of(3)]), Some(vec![]));
+        assert_eq!(index.candidates(3, 5, &[bloom_of(3)]), None);
+    }
+
+    #[test]
+    fn should_keep_only_latest_blocks() {
+        let index = BloomIndex::new(2);
+        index.update(4, |n| Some((H256::from(n), Bloom::default())));
+
+        assert_eq!(index.candidates(2, 4, &[Bloom::default()]), None);
+        assert_eq!(
+            index.candidates(3, 4, &[Bloom::default()]),
+            Some(vec![3, 4])
+        );
+    }
+
+    #[test]
+    fn should_drop_reorganised_blocks() {
+        let index = BloomIndex::new(8);
+        index.update(3, |n| Some((H256::from(n), bloom_of(1))));
+        index.update(3, |n| {
+            let hash = if n == 3 {
+                H256::from(0xff)
+            } else {
+                H256::from(n)
+            };
+            Some((hash, Bloom::default()))
+        });
+
+        assert_eq!(index.candidates(0, 3, &[bloom_of(1)]), Some(vec![0, 1, 2]));
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of OpenEthereum.
+
+// OpenEthereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// OpenEthereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Dapps Service
+
+use std::collections::VecDeque;
+
+use parking_lot::Mutex;
+use v1::types::LocalDapp;
+
+/// Default number of dapps remembered by `RecentDapps`.
+pub const MAX_RECENT_DAPPS: usize = 50;
+
+/// Dapps Server service.
+pub trait DappsService: Send + Sync + 'static {
+    /// List available local dapps.
+    fn list_dapps(&self) -> Vec<LocalDapp>;
+
+    /// Re-scan the local dapps directory. Returns `true` on success.
+    fn refresh_local_dapps(&self) -> bool;
+
+    /// Base URL of the gateway serving content-addressed dapps, e.g. `http://127.0.0.1:8080`.
+    fn content_gateway(&self) -> String;
+}
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz234567";
+
+/// Returns the URL under which `gateway` serves content with given CID.
+///
+/// Accepts base58 encoded CIDv0 (`Qm...`) and base32 encoded CIDv1 (`ba...`) identifiers,
+/// returns `None` if `cid` is neither.
+pub fn cid_to_url(gateway: &str, cid: &str) -> Option<String> {
+    let is_v0 = cid.len() == 46
+        && cid.starts_with("Qm")
+        && cid.chars().all(|c| BASE58_ALPHABET.contains(c));
+    let is_v1 = cid.len() > 2
+        && cid.starts_with("ba")
+        && cid[1..].chars().all(|c| BASE32_ALPHABET.contains(c));
+
+    if is_v0 || is_v1 {
+        Some(format!("{}/ipfs/{}", gateway.trim_end_matches('/'), cid))
+    } else {
+        None
+    }
+}
+
+/// Dapps which recently made requests, ordered by last use.
+pub struct RecentDapps {
+    capacity: usize,
+    dapps: Mutex<VecDeque<String>>,
+}
+
+impl Default for RecentDapps {
+    fn default() -> Self {
+        RecentDapps::new(MAX_RECENT_DAPPS)
+    }
+}
+
+impl RecentDapps {
+    /// Creates a history remembering at most `capacity` dapps.
+    pub fn new(capacity: usize) -> Self {
+        RecentDapps {
+            capacity,
+            dapps: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Marks given dapp as the most recently used one.
+    pub fn note_used(&self, id: &str) {
+        let mut dapps = self.dapps.lock();
+        dapps.retain(|dapp| dapp != id);
+        dapps.push_front(id.to_owned());
+        dapps.truncate(self.capacity);
+    }
+
+    /// Returns ids of recently used dapps, most recent first.
+    pub fn list(&self) -> Vec<String> {
+        self.dapps.lock().iter().cloned().collect()
+    }
+}
@@ -102,6 +102,7 @@ use v1::{
         Bytes as RpcBytes, ConfirmationPayload as RpcConfirmationPayload, ConfirmationResponse,
         DecryptRequest as RpcDecryptRequest, EIP191SignRequest as RpcSignRequest,
         EthSignRequest as RpcEthSignRequest, RichRawTransaction as RpcRichRawTransaction,
+        SentTransaction as RpcSentTransaction,
     },
 };
 
@@ -374,6 +375,27 @@ pub fn execute<D: Dispatcher + 'static>(
     }
 }
 
+/// Sign and dispatch a filled transaction request,
+/// returning the hash together with the nonce, gas and gas price the transaction was signed with.
+pub fn send_detailed<D: Dispatcher + 'static>(
+    dispatcher: D,
+    signer: &Arc<dyn Accounts>,
+    request: FilledTransactionRequest,
+    pass: SignWith,
+) -> BoxFuture<RpcSentTransaction> {
+    let condition = request.condition.clone().map(Into::into);
+    let cloned_dispatcher = dispatcher.clone();
+    let post_sign = move |with_token_signed: WithToken<SignedTransaction>| {
+        let signed = with_token_signed.into_value();
+        let sent = RpcSentTransaction::from_signed(&signed);
+        cloned_dispatcher
+            .dispatch_transaction(PendingTransaction::new(signed, condition))
+            .map(|hash| RpcSentTransaction { hash, ..sent })
+    };
+
+    dispatcher.sign(request, signer, pass, post_sign)
+}
+
 /// Returns a eth_sign-compatible hash of data to sign.
 /// The data is prepended with special message to prevent
 /// malicious DApps from using the function to sign forged transactions.
@@ -305,6 +305,22 @@ pub fn ws_disabled() -> Error {
     }
 }
 
+pub fn dapps_disabled() -> Error {
+    Error {
+        code: ErrorCode::ServerError(codes::UNSUPPORTED_REQUEST),
+        message: "Dapps Server is disabled. This API is not available.".into(),
+        data: None,
+    }
+}
+
+pub fn updater_disabled() -> Error {
+    Error {
+        code: ErrorCode::ServerError(codes::UNSUPPORTED_REQUEST),
+        message: "Updater is disabled. This API is not available.".into(),
+        data: None,
+    }
+}
+
 pub fn network_disabled() -> Error {
     Error {
         code: ErrorCode::ServerError(codes::UNSUPPORTED_REQUEST),
@@ -386,7 +402,7 @@ pub fn transaction_message(error: &TransactionError) -> String {
 			format!("Transaction gas price is too low. It does not satisfy your node's minimal gas price (minimal: {}, got: {}). Try increasing the gas price.", minimal, got)
 		}
 		InsufficientBalance { balance, cost } => {
-			format!("Insufficient funds. The account you tried to send transaction from does not have enough funds. Required {} and got: {}.", cost, balance)
+			format!("Insufficient funds. The account you tried to send transaction from does not have enough funds. Required {} and got: {}, missing: {}.", cost, balance, cost.saturating_sub(balance))
 		}
 		GasLimitExceeded { limit, got } => {
 			format!("Transaction cost exceeds current gas limit. Limit: {}, got: {}. Try decreasing supplied gas.", limit, got)
@@ -404,13 +420,39 @@ pub fn transaction_message(error: &TransactionError) -> String {
 	}
 }
 
+/// Machine-readable details of a rejected transaction, if there are any.
+fn transaction_data(error: &TransactionError) -> Option<Value> {
+    match *error {
+        TransactionError::InsufficientBalance { balance, cost } => {
+            let mut data = ::serde_json::Map::new();
+            data.insert("required".into(), Value::String(format!("0x{:x}", cost)));
+            data.insert(
+                "available".into(),
+                Value::String(format!("0x{:x}", balance)),
+            );
+            data.insert(
+                "shortfall".into(),
+                Value::String(format!("0x{:x}", cost.saturating_sub(balance))),
+            );
+            Some(Value::Object(data))
+        }
+        TransactionError::InsufficientGasPrice { minimal, got } => {
+            let mut data = ::serde_json::Map::new();
+            data.insert("minimal".into(), Value::String(format!("0x{:x}", minimal)));
+            data.insert("got".into(), Value::String(format!("0x{:x}", got)));
+            Some(Value::Object(data))
+        }
+        _ => None,
+    }
+}
+
 pub fn transaction<T: Into<EthcoreError>>(error: T) -> Error {
     let error = error.into();
     if let ErrorKind::Transaction(ref e) = *error.kind() {
         Error {
             code: ErrorCode::ServerError(codes::TRANSACTION_ERROR),
             message: transaction_message(e),
-            data: None,
+            data: transaction_data(e),
         }
     } else {
         Error {
@@ -477,6 +519,17 @@ pub fn unknown_block() -> Error {
     }
 }
 
+pub fn future_block(number: u64, best_block: u64) -> Error {
+    Error {
+        code: ErrorCode::InvalidParams,
+        message: format!(
+            "Block #{} not found: it is beyond the current head #{}",
+            number, best_block
+        ),
+        data: None,
+    }
+}
+
 pub fn deprecated<S: Into<String>, T: Into<Option<S>>>(message: T) -> Error {
     Error {
         code: ErrorCode::ServerError(codes::DEPRECATED),
@@ -493,6 +546,39 @@ pub fn filter_not_found() -> Error {
     }
 }
 
+pub fn too_many_blocks(blocks: u64, max_blocks: u64) -> Error {
+    Error {
+        code: ErrorCode::ServerError(codes::REQUEST_REJECTED_LIMIT),
+        message: format!(
+            "Too many blocks: query spans {} blocks, the limit is {}",
+            blocks, max_blocks
+        ),
+        data: None,
+    }
+}
+
+pub fn too_many_logs(max_logs: usize) -> Error {
+    Error {
+        code: ErrorCode::ServerError(codes::REQUEST_REJECTED_LIMIT),
+        message: format!(
+            "Too many results: query matches more than {} logs",
+            max_logs
+        ),
+        data: None,
+    }
+}
+
+pub fn not_a_contract<T: fmt::LowerHex>(address: T) -> Error {
+    Error {
+        code: ErrorCode::InvalidParams,
+        message: format!(
+            "Account 0x{:x} has no code, so it cannot have any storage.",
+            address
+        ),
+        data: None,
+    }
+}
+
 pub fn filter_block_not_found(id: BlockId) -> Error {
     Error {
 		code: ErrorCode::ServerError(codes::UNSUPPORTED_REQUEST), // Specified in EIP-234.
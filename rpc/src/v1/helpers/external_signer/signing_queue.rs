@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use super::oneshot;
 use ethereum_types::U256;
@@ -189,6 +192,10 @@ impl SigningQueue for ConfirmationsQueue {
                         id,
                         payload: request,
                         origin,
+                        timestamp: SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .map(|d| d.as_secs())
+                            .unwrap_or(0),
                     },
                 },
             );
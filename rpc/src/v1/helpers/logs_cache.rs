@@ -0,0 +1,166 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of OpenEthereum.
+
+// OpenEthereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// OpenEthereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Cache of `eth_getLogs` results for finalized block ranges.
+
+use std::collections::{HashMap, VecDeque};
+
+use ethereum_types::{Address, H256};
+use parking_lot::Mutex;
+use types::{filter::Filter, BlockNumber};
+use v1::types::Log;
+
+/// Filter with its block range resolved to block numbers.
+///
+/// The hash of the last block pins the key to the chain it was queried on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LogsCacheKey {
+    from_block: BlockNumber,
+    to_block: BlockNumber,
+    to_block_hash: H256,
+    address: Option<Vec<Address>>,
+    topics: Vec<Option<Vec<H256>>>,
+    limit: Option<usize>,
+}
+
+impl LogsCacheKey {
+    /// Creates a key for `filter` queried over `from_block..=to_block`,
+    /// where `to_block_hash` is the hash of the canonical block `to_block`.
+    pub fn new(
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+        to_block_hash: H256,
+        filter: &Filter,
+    ) -> Self {
+        LogsCacheKey {
+            from_block,
+            to_block,
+            to_block_hash,
+            address: filter.address.clone(),
+            topics: filter.topics.clone(),
+            limit: filter.limit,
+        }
+    }
+}
+
+struct Entries {
+    logs: HashMap<LogsCacheKey, Vec<Log>>,
+    order: VecDeque<LogsCacheKey>,
+}
+
+/// Bounded cache of logs matched over finalized ranges.
+///
+/// Entries are never invalidated. A reorg deeper than the confirmation depth
+/// changes the hash of the last block in a range, so entries for the retracted
+/// chain are no longer hit and eventually evicted; once the cache is full the
+/// oldest entry is evicted.
+pub struct LogsCache {
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+impl LogsCache {
+    /// Creates a cache holding at most `capacity` results.
+    pub fn new(capacity: usize) -> Self {
+        LogsCache {
+            capacity,
+            entries: Mutex::new(Entries {
+                logs: HashMap::new(),
+                order: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Returns cached logs for given key.
+    pub fn get(&self, key: &LogsCacheKey) -> Option<Vec<Log>> {
+        self.entries.lock().logs.get(key).cloned()
+    }
+
+    /// Stores logs for given key, evicting the oldest entries if needed.
+    pub fn insert(&self, key: LogsCacheKey, logs: Vec<Log>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock();
+        if entries.logs.contains_key(&key) {
+            return;
+        }
+
+        while entries.order.len() >= self.capacity {
+            match entries.order.pop_front() {
+                Some(oldest) => {
+                    entries.logs.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+
+        entries.order.push_back(key.clone());
+        entries.logs.insert(key, logs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LogsCache, LogsCacheKey};
+    use ethereum_types::H256;
+    use types::{filter::Filter, ids::BlockId};
+
+    fn key(from: u64, to: u64) -> LogsCacheKey {
+        key_on_chain(from, to, H256::from(to))
+    }
+
+    fn key_on_chain(from: u64, to: u64, to_block_hash: H256) -> LogsCacheKey {
+        let filter = Filter {
+            from_block: BlockId::Number(from),
+            to_block: BlockId::Number(to),
+            address: None,
+            topics: vec![],
+            limit: None,
+        };
+        LogsCacheKey::new(from, to, to_block_hash, &filter)
+    }
+
+    #[test]
+    fn should_evict_oldest_entry_when_full() {
+        let cache = LogsCache::new(2);
+        cache.insert(key(0, 1), vec![]);
+        cache.insert(key(1, 2), vec![]);
+        cache.insert(key(2, 3), vec![]);
+
+        assert!(cache.get(&key(0, 1)).is_none());
+        assert!(cache.get(&key(1, 2)).is_some());
+        assert!(cache.get(&key(2, 3)).is_some());
+    }
+
+    #[test]
+    fn should_not_return_logs_of_a_retracted_block() {
+        let cache = LogsCache::new(2);
+        cache.insert(key_on_chain(0, 1, H256::from(1)), vec![]);
+
+        assert!(cache.get(&key_on_chain(0, 1, H256::from(1))).is_some());
+        assert!(cache.get(&key_on_chain(0, 1, H256::from(2))).is_none());
+    }
+
+    #[test]
+    fn should_not_store_anything_with_zero_capacity() {
+        let cache = LogsCache::new(0);
+        cache.insert(key(0, 1), vec![]);
+
+        assert!(cache.get(&key(0, 1)).is_none());
+    }
+}
@@ -18,6 +18,7 @@
 pub mod errors;
 
 pub mod block_import;
+pub mod dapps;
 pub mod deprecated;
 pub mod dispatch;
 #[cfg(any(test, feature = "accounts"))]
@@ -29,25 +30,32 @@ pub mod fake_sign;
 pub mod nonce;
 #[cfg(any(test, feature = "accounts"))]
 pub mod secretstore;
+pub mod updater;
 
+mod bloom_index;
+mod logs_cache;
 mod network_settings;
 mod poll_filter;
 mod poll_manager;
 mod requests;
+mod revert_reason;
 mod signature;
 mod subscribers;
 mod subscription_manager;
 mod work;
 
 pub use self::{
+    bloom_index::{BloomIndex, BloomIndexHandler},
     dispatch::{Dispatcher, FullDispatcher},
-    network_settings::NetworkSettings,
-    poll_filter::{limit_logs, PollFilter, SyncPollFilter},
+    logs_cache::{LogsCache, LogsCacheKey},
+    network_settings::{NetworkSettings, NodeName},
+    poll_filter::{dedup_logs, limit_logs, PollFilter, SyncPollFilter},
     poll_manager::PollManager,
     requests::{
         CallRequest, ConfirmationPayload, ConfirmationRequest, FilledTransactionRequest,
         TransactionRequest,
     },
+    revert_reason::decode_revert_reason,
     signature::verify_signature,
     subscribers::Subscribers,
     subscription_manager::GenericPollManager,
@@ -16,6 +16,12 @@
 
 //! Structure to hold network settings configured from CLI
 
+use parking_lot::RwLock;
+use version::{version, version_data};
+
+/// Maximal length of the extra data derived from the node name.
+const MAX_EXTRA_DATA_SIZE: usize = 32;
+
 /// Networking & RPC settings
 #[derive(Debug, PartialEq, Clone)]
 pub struct NetworkSettings {
@@ -33,6 +39,10 @@ pub struct NetworkSettings {
     pub rpc_interface: String,
     /// Port for JSON-RPC server
     pub rpc_port: u16,
+    /// Is WebSockets server enabled?
+    pub ws_enabled: bool,
+    /// Port for WebSockets server
+    pub ws_port: u16,
 }
 
 impl Default for NetworkSettings {
@@ -45,6 +55,51 @@ impl Default for NetworkSettings {
             rpc_enabled: true,
             rpc_interface: "127.0.0.1".into(),
             rpc_port: 8545,
+            ws_enabled: true,
+            ws_port: 8546,
         }
     }
 }
+
+/// Name of this node, shared between the APIs that report or change it at runtime.
+#[derive(Debug, Default)]
+pub struct NodeName(RwLock<String>);
+
+impl NodeName {
+    /// Creates a new node name, usually the one configured with `--identity`.
+    pub fn new(name: String) -> Self {
+        NodeName(RwLock::new(name))
+    }
+
+    /// Returns the current node name.
+    pub fn get(&self) -> String {
+        self.0.read().clone()
+    }
+
+    /// Replaces the node name.
+    pub fn set(&self, name: String) {
+        *self.0.write() = name;
+    }
+
+    /// Returns the client version string with the node name in its identity slot.
+    pub fn client_version(&self) -> String {
+        version().replacen("/", &format!("/{}/", self.get()), 1)
+    }
+
+    /// Returns the extra data sealed blocks carry by default: the node name cut to
+    /// 32 bytes, or the version data if no name is set.
+    pub fn extra_data(&self) -> Vec<u8> {
+        let name = self.get();
+        if name.is_empty() {
+            return version_data();
+        }
+        let mut extra_data = name.into_bytes();
+        extra_data.truncate(MAX_EXTRA_DATA_SIZE);
+        extra_data
+    }
+
+    /// Returns true if `extra_data` is a default one rather than set explicitly.
+    pub fn is_default_extra_data(&self, extra_data: &[u8]) -> bool {
+        extra_data == &version_data()[..] || extra_data == &self.extra_data()[..]
+    }
+}
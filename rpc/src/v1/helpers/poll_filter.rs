@@ -71,6 +71,19 @@ impl PollFilter {
     pub(in v1) const MAX_BLOCK_HISTORY_SIZE: usize = 32;
 }
 
+/// Removes repeated mined logs, keeping their first occurrence.
+///
+/// Logs are identified by block hash and log index; pending logs have neither and are all kept.
+pub fn dedup_logs(logs: Vec<Log>) -> Vec<Log> {
+    let mut seen = HashSet::new();
+    logs.into_iter()
+        .filter(|log| match (log.block_hash, log.log_index) {
+            (Some(hash), Some(index)) => seen.insert((hash, index)),
+            _ => true,
+        })
+        .collect()
+}
+
 /// Returns only last `n` logs
 pub fn limit_logs(mut logs: Vec<Log>, limit: Option<usize>) -> Vec<Log> {
     let len = logs.len();
@@ -121,6 +121,8 @@ pub struct ConfirmationRequest {
     pub payload: ConfirmationPayload,
     /// Request origin
     pub origin: Origin,
+    /// Time the request was queued, in seconds since the UNIX epoch
+    pub timestamp: u64,
 }
 
 /// Payload to confirm in Trusted Signer
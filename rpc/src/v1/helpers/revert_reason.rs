@@ -0,0 +1,78 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of OpenEthereum.
+
+// OpenEthereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// OpenEthereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Decoding of revert data returned by `REVERT`.
+
+use ethereum_types::U256;
+use rustc_hex::ToHex;
+
+/// Selector of `Error(string)`, used by Solidity for `require` and `revert` messages.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Returns the message of `Error(string)` revert data, or the hex-encoded data
+/// if it is not in that format.
+pub fn decode_revert_reason(output: &[u8]) -> String {
+    decode_error_string(output).unwrap_or_else(|| format!("0x{}", output.to_hex()))
+}
+
+fn decode_error_string(output: &[u8]) -> Option<String> {
+    if output.len() < 4 || output[..4] != ERROR_SELECTOR {
+        return None;
+    }
+    let data = &output[4..];
+    let word = |offset: usize| -> Option<usize> {
+        let word = data.get(offset..offset.checked_add(32)?)?;
+        let value = U256::from(word);
+        if value > U256::from(data.len()) {
+            return None;
+        }
+        Some(value.low_u64() as usize)
+    };
+
+    let offset = word(0)?;
+    let len = word(offset)?;
+    let start = offset.checked_add(32)?;
+    let message = data.get(start..start.checked_add(len)?)?;
+    String::from_utf8(message.to_vec()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_revert_reason;
+    use rustc_hex::FromHex;
+
+    #[test]
+    fn should_decode_error_string() {
+        let output: Vec<u8> = "08c379a0\
+			0000000000000000000000000000000000000000000000000000000000000020\
+			000000000000000000000000000000000000000000000000000000000000000e\
+			4e6f7420656e6f75676820455448000000000000000000000000000000000000"
+            .from_hex()
+            .unwrap();
+
+        assert_eq!(decode_revert_reason(&output), "Not enough ETH");
+    }
+
+    #[test]
+    fn should_fall_back_to_hex() {
+        assert_eq!(decode_revert_reason(&[]), "0x");
+        assert_eq!(decode_revert_reason(&[0x12, 0x34]), "0x1234");
+        assert_eq!(
+            decode_revert_reason(&[0x08, 0xc3, 0x79, 0xa0, 0x01]),
+            "0x08c379a001"
+        );
+    }
+}
@@ -17,7 +17,8 @@
 //! Eth rpc implementation.
 
 use std::{
-    sync::Arc,
+    cmp,
+    sync::{Arc, Weak},
     thread,
     time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
@@ -41,26 +42,29 @@ use types::{
     encoded,
     filter::Filter as EthcoreFilter,
     header::Header,
+    log_entry::LocalizedLogEntry,
     transaction::{LocalizedTransaction, SignedTransaction, TypedTransaction},
     BlockNumber as EthBlockNumber,
 };
+use vm::Error as VMError;
 
 use jsonrpc_core::{futures::future, BoxFuture, Result};
 
 use v1::{
     helpers::{
         self,
-        block_import::is_major_importing,
+        block_import::sync_status,
+        decode_revert_reason, dedup_logs,
         deprecated::{self, DeprecationNotice},
         dispatch::{default_gas_price, FullDispatcher},
-        errors, fake_sign, limit_logs,
+        errors, fake_sign, limit_logs, BloomIndexHandler, LogsCache, LogsCacheKey,
     },
     metadata::Metadata,
     traits::Eth,
     types::{
-        block_number_to_id, Block, BlockNumber, BlockTransactions, Bytes, CallRequest, EthAccount,
-        Filter, Index, Log, Receipt, RichBlock, StorageProof, SyncInfo, SyncStatus, Transaction,
-        Work,
+        block_number_to_id, Block, BlockNumber, BlockTransactions, Bytes, CallRequest, Either,
+        EthAccount, Filter, Index, Log, Receipt, RichBlock, StorageProof, SubmittedTransaction,
+        SyncStatus, Transaction, Work,
     },
 };
 
@@ -78,6 +82,25 @@ pub struct EthClientOptions {
     pub allow_experimental_rpcs: bool,
     /// flag for ancient block sync
     pub no_ancient_blocks: bool,
+    /// Maximal number of `eth_getLogs` results to cache for finalized ranges.
+    /// Caching is disabled when set to `0`.
+    pub logs_cache_size: usize,
+    /// Number of confirmations after which a block is considered final
+    /// and logs from it may be cached. Entries are keyed by the hash of the
+    /// last block, so a deeper reorg only makes them stale, never wrong.
+    pub logs_cache_confirmations: u64,
+    /// Number of recent blocks whose blooms are kept in memory to pick
+    /// candidate blocks for `eth_getLogs`. Disabled when set to `0`.
+    pub logs_bloom_index_size: usize,
+    /// Re-execute failed transactions to include the revert reason in
+    /// `eth_getTransactionReceipt` responses.
+    pub receipt_revert_reason: bool,
+    /// Maximal number of blocks a single `eth_getLogs` may span.
+    /// Unlimited when set to `0`.
+    pub logs_max_blocks: u64,
+    /// Maximal number of logs a single `eth_getLogs` may return.
+    /// Unlimited when set to `0`.
+    pub logs_max_results: usize,
 }
 
 impl EthClientOptions {
@@ -97,6 +120,12 @@ impl Default for EthClientOptions {
             allow_missing_blocks: false,
             allow_experimental_rpcs: false,
             no_ancient_blocks: false,
+            logs_cache_size: 0,
+            logs_cache_confirmations: 64,
+            logs_bloom_index_size: 0,
+            receipt_revert_reason: false,
+            logs_max_blocks: 0,
+            logs_max_results: 0,
         }
     }
 }
@@ -119,6 +148,8 @@ where
     seed_compute: Mutex<SeedHashCompute>,
     options: EthClientOptions,
     deprecation_notice: DeprecationNotice,
+    logs_cache: LogsCache,
+    bloom_index: Arc<BloomIndexHandler<C>>,
 }
 
 #[derive(Debug)]
@@ -186,6 +217,108 @@ where
             seed_compute: Mutex::new(SeedHashCompute::default()),
             options,
             deprecation_notice: Default::default(),
+            logs_cache: LogsCache::new(options.logs_cache_size),
+            bloom_index: Arc::new(BloomIndexHandler::new(
+                client.clone(),
+                options.logs_bloom_index_size,
+            )),
+        }
+    }
+
+    /// Returns a chain notification handler keeping the `eth_getLogs` bloom index up to date.
+    pub fn bloom_index_handler(&self) -> Weak<BloomIndexHandler<C>> {
+        Arc::downgrade(&self.bloom_index)
+    }
+
+    /// Returns a logs cache key if caching is enabled and the whole filtered range has
+    /// enough confirmations.
+    fn finalized_logs_cache_key(&self, filter: &EthcoreFilter) -> Option<LogsCacheKey> {
+        if self.options.logs_cache_size == 0 {
+            return None;
+        }
+
+        let best_block = self.client.chain_info().best_block_number;
+        let finalized = best_block.checked_sub(self.options.logs_cache_confirmations)?;
+        let from_block = self.client.block_number(filter.from_block)?;
+        let to_block = self.client.block_number(filter.to_block)?;
+        if to_block > finalized || from_block > to_block {
+            return None;
+        }
+
+        let to_block_hash = self.client.block_hash(BlockId::Number(to_block))?;
+        Some(LogsCacheKey::new(
+            from_block,
+            to_block,
+            to_block_hash,
+            filter,
+        ))
+    }
+
+    /// Returns logs matching the filter from the client.
+    ///
+    /// If the bloom index covers the filtered range only blocks whose bloom
+    /// matches the filter are queried.
+    fn client_logs(&self, filter: &EthcoreFilter) -> Result<Vec<Log>> {
+        let candidates = if self.options.logs_bloom_index_size == 0 {
+            None
+        } else {
+            match (
+                self.client.block_number(filter.from_block),
+                self.client.block_number(filter.to_block),
+            ) {
+                (Some(from_block), Some(to_block)) => {
+                    self.bloom_index
+                        .candidates(from_block, to_block, &filter.bloom_possibilities())
+                }
+                _ => None,
+            }
+        };
+
+        let logs: Vec<LocalizedLogEntry> = match candidates {
+            None => self
+                .client
+                .logs(filter.clone())
+                .map_err(errors::filter_block_not_found)?,
+            Some(candidates) => {
+                // the limit keeps the last logs, so walk backwards and stop once it is reached
+                let mut blocks = Vec::new();
+                let mut count = 0;
+                for number in candidates.into_iter().rev() {
+                    if filter.limit.map_or(false, |limit| count >= limit) {
+                        break;
+                    }
+                    let mut block_filter = filter.clone();
+                    block_filter.from_block = BlockId::Number(number);
+                    block_filter.to_block = BlockId::Number(number);
+                    block_filter.limit = None;
+                    let block_logs = self
+                        .client
+                        .logs(block_filter)
+                        .map_err(errors::filter_block_not_found)?;
+                    count += block_logs.len();
+                    blocks.push(block_logs);
+                }
+                blocks.into_iter().rev().flatten().collect()
+            }
+        };
+
+        Ok(logs.into_iter().map(From::from).collect())
+    }
+
+    /// Re-executes a failed transaction and returns the reason it reverted.
+    fn revert_reason(&self, receipt: &Receipt, hash: H256) -> Option<String> {
+        if receipt.status_code != Some(U64::zero()) {
+            return None;
+        }
+
+        let executed = self
+            .client
+            .replay(TransactionId::Hash(hash), Default::default())
+            .ok()?;
+        match executed.exception {
+            Some(VMError::Reverted) => Some(decode_revert_reason(&executed.output)),
+            Some(ref error) => Some(error.to_string()),
+            None => None,
         }
     }
 
@@ -258,6 +391,13 @@ where
         match (block, difficulty) {
             (Some(block), Some(total_difficulty)) => {
                 let view = block.header_view();
+                // engines like Clique don't put the sealing authority in the author field,
+                // so ask the engine who actually produced the block.
+                let miner = self
+                    .client
+                    .engine()
+                    .executive_author(&block.decode_header())
+                    .unwrap_or_else(|_| view.author());
                 Ok(Some(RichBlock {
                     inner: Block {
                         hash: match is_pending {
@@ -268,7 +408,7 @@ where
                         parent_hash: view.parent_hash(),
                         uncles_hash: view.uncles_hash(),
                         author: view.author(),
-                        miner: view.author(),
+                        miner,
                         state_root: view.state_root(),
                         transactions_root: view.transactions_root(),
                         receipts_root: view.receipts_root(),
@@ -536,7 +676,13 @@ where
 
     let id = match number {
         BlockNumber::Pending => return Ok(()),
-        BlockNumber::Num(n) => BlockId::Number(n),
+        BlockNumber::Num(n) => {
+            let best_block = client.chain_info().best_block_number;
+            if n > best_block {
+                return Err(errors::future_block(n, best_block));
+            }
+            BlockId::Number(n)
+        }
         BlockNumber::Latest => BlockId::Latest,
         BlockNumber::Earliest => BlockId::Earliest,
     };
@@ -570,51 +716,7 @@ where
     }
 
     fn syncing(&self) -> Result<SyncStatus> {
-        use ethcore::snapshot::RestorationStatus;
-
-        let status = self.sync.status();
-        let client = &self.client;
-        let snapshot_status = self.snapshot.restoration_status();
-
-        let (warping, warp_chunks_amount, warp_chunks_processed) = match snapshot_status {
-            RestorationStatus::Ongoing {
-                state_chunks,
-                block_chunks,
-                state_chunks_done,
-                block_chunks_done,
-                ..
-            } => (
-                true,
-                Some(block_chunks + state_chunks),
-                Some(block_chunks_done + state_chunks_done),
-            ),
-            _ => (false, None, None),
-        };
-
-        if warping || is_major_importing(Some(status.state), client.queue_info()) {
-            let chain_info = client.chain_info();
-            let current_block = U256::from(chain_info.best_block_number);
-            let highest_block = U256::from(
-                status
-                    .highest_block_number
-                    .unwrap_or(status.start_block_number),
-            );
-
-            let info = SyncInfo {
-                starting_block: status.start_block_number.into(),
-                current_block,
-                highest_block,
-                warp_chunks_amount: warp_chunks_amount
-                    .map(|x| U256::from(x as u64))
-                    .map(Into::into),
-                warp_chunks_processed: warp_chunks_processed
-                    .map(|x| U256::from(x as u64))
-                    .map(Into::into),
-            };
-            Ok(SyncStatus::Info(info))
-        } else {
-            Ok(SyncStatus::None)
-        }
+        Ok(sync_status(&*self.client, &*self.snapshot, &*self.sync))
     }
 
     fn author(&self) -> Result<H160> {
@@ -916,9 +1018,17 @@ where
             return Box::new(future::ok(Some(receipt.into())));
         }
 
-        let receipt = self.client.transaction_receipt(TransactionId::Hash(hash));
-        let result = Ok(receipt.map(Into::into))
-            .and_then(errors::check_block_gap(&*self.client, self.options));
+        let receipt = self
+            .client
+            .transaction_receipt(TransactionId::Hash(hash))
+            .map(|receipt| {
+                let mut receipt = Receipt::from(receipt);
+                if self.options.receipt_revert_reason {
+                    receipt.revert_reason = self.revert_reason(&receipt, hash);
+                }
+                receipt
+            });
+        let result = Ok(receipt).and_then(errors::check_block_gap(&*self.client, self.options));
         Box::new(future::done(result))
     }
 
@@ -980,13 +1090,50 @@ where
 
     fn logs(&self, filter: Filter) -> BoxFuture<Vec<Log>> {
         let include_pending = filter.to_block == Some(BlockNumber::Pending);
-        let filter: EthcoreFilter = match filter.try_into() {
+        let mut filter: EthcoreFilter = match filter.try_into() {
             Ok(value) => value,
             Err(err) => return Box::new(future::err(err)),
         };
-        let mut logs = match self.client.logs(filter.clone()) {
-            Ok(logs) => logs.into_iter().map(From::from).collect::<Vec<Log>>(),
-            Err(id) => return Box::new(future::err(errors::filter_block_not_found(id))),
+        let max_results = self.options.logs_max_results;
+        if max_results > 0 {
+            // fetching one log over the cap is enough to tell the query matches too many
+            let cap = max_results.saturating_add(1);
+            filter.limit = Some(filter.limit.map_or(cap, |limit| cmp::min(limit, cap)));
+        }
+        let too_many = |count: usize| max_results > 0 && count > max_results;
+        if self.options.logs_max_blocks > 0 {
+            if let (Some(from_block), Some(to_block)) = (
+                self.client.block_number(filter.from_block),
+                self.client.block_number(filter.to_block),
+            ) {
+                let blocks = (to_block + 1).saturating_sub(from_block);
+                if blocks > self.options.logs_max_blocks {
+                    return Box::new(future::err(errors::too_many_blocks(
+                        blocks,
+                        self.options.logs_max_blocks,
+                    )));
+                }
+            }
+        }
+        let cache_key = if include_pending {
+            None
+        } else {
+            self.finalized_logs_cache_key(&filter)
+        };
+        let cached = cache_key.as_ref().and_then(|key| self.logs_cache.get(key));
+        let mut logs = match cached {
+            Some(logs) => logs,
+            None => match self.client_logs(&filter) {
+                Ok(logs) => {
+                    if let Some(key) = cache_key {
+                        if !too_many(logs.len()) {
+                            self.logs_cache.insert(key, logs.clone());
+                        }
+                    }
+                    logs
+                }
+                Err(err) => return Box::new(future::err(err)),
+            },
         };
 
         if include_pending {
@@ -995,7 +1142,10 @@ where
             logs.extend(pending);
         }
 
-        let logs = limit_logs(logs, filter.limit);
+        let logs = limit_logs(dedup_logs(logs), filter.limit);
+        if too_many(logs.len()) {
+            return Box::new(future::err(errors::too_many_logs(max_results)));
+        }
 
         Box::new(future::ok(logs))
     }
@@ -1063,7 +1213,23 @@ where
         Ok(true)
     }
 
-    fn send_raw_transaction(&self, raw: Bytes) -> Result<H256> {
+    fn send_raw_transaction(
+        &self,
+        raw: Bytes,
+        detailed: Option<bool>,
+    ) -> Result<Either<H256, SubmittedTransaction>> {
+        let hash = self.submit_transaction(raw)?;
+        Ok(if detailed.unwrap_or(false) {
+            Either::Or(SubmittedTransaction {
+                hash,
+                queue_pressure: self.miner.queue_pressure(),
+            })
+        } else {
+            hash.into()
+        })
+    }
+
+    fn submit_transaction(&self, raw: Bytes) -> Result<H256> {
         TypedTransaction::decode(&raw.into_vec())
             .map_err(errors::rlp)
             .and_then(|tx| SignedTransaction::new(tx).map_err(errors::transaction))
@@ -1078,10 +1244,6 @@ where
             .map(Into::into)
     }
 
-    fn submit_transaction(&self, raw: Bytes) -> Result<H256> {
-        self.send_raw_transaction(raw)
-    }
-
     fn call(&self, request: CallRequest, num: Option<BlockNumber>) -> BoxFuture<Bytes> {
         let request = CallRequest::into(request);
         let signed = try_bf!(fake_sign::sign_call(request));
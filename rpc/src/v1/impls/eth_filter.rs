@@ -34,7 +34,7 @@ use jsonrpc_core::{
     BoxFuture, Result,
 };
 use v1::{
-    helpers::{errors, limit_logs, PollFilter, PollManager, SyncPollFilter},
+    helpers::{dedup_logs, errors, limit_logs, PollFilter, PollManager, SyncPollFilter},
     impls::eth::pending_logs,
     traits::EthFilter,
     types::{BlockNumber, Filter, FilterChanges, Index, Log},
@@ -320,6 +320,7 @@ impl<T: Filterable + Send + Sync + 'static> EthFilter for T {
                             logs.extend(pending);
                             logs
                         }) // append fetched pending logs
+                        .map(dedup_logs) // drop logs of blocks scanned twice
                         .map(move |logs| limit_logs(logs, limit)) // limit the logs
                         .map(FilterChanges::Logs),
                 )
@@ -362,6 +363,7 @@ impl<T: Filterable + Send + Sync + 'static> EthFilter for T {
                 logs.extend(pending);
                 logs
             })
+            .map(dedup_logs)
             .map(move |logs| limit_logs(logs, limit)),
         )
     }
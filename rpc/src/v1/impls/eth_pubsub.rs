@@ -19,10 +19,11 @@
 use std::{
     collections::BTreeMap,
     sync::{Arc, Weak},
+    time::Duration,
 };
 
 use jsonrpc_core::{
-    futures::{self, Future, IntoFuture},
+    futures::{self, Future, IntoFuture, Stream},
     Error, Result,
 };
 use jsonrpc_pubsub::{
@@ -31,16 +32,21 @@ use jsonrpc_pubsub::{
 };
 
 use v1::{
-    helpers::{errors, limit_logs, Subscribers},
+    helpers::{block_import::sync_status, errors, limit_logs, Subscribers},
     metadata::Metadata,
     traits::EthPubSub,
-    types::{pubsub, Log, RichHeader},
+    types::{pubsub, Log, RichHeader, SyncStatus},
 };
 
-use ethcore::client::{BlockChainClient, BlockId, ChainNotify, ChainRouteType, NewBlocks};
+use ethcore::{
+    client::{BlockChainClient, BlockId, ChainNotify, ChainRouteType, NewBlocks},
+    snapshot::SnapshotService,
+};
 use ethereum_types::H256;
 use parity_runtime::Executor;
 use parking_lot::RwLock;
+use sync::SyncProvider;
+use tokio_timer;
 
 use types::{encoded, filter::Filter as EthFilter};
 
@@ -52,6 +58,7 @@ pub struct EthPubSubClient<C> {
     heads_subscribers: Arc<RwLock<Subscribers<Client>>>,
     logs_subscribers: Arc<RwLock<Subscribers<(Client, EthFilter)>>>,
     transactions_subscribers: Arc<RwLock<Subscribers<Client>>>,
+    syncing_subscribers: Arc<RwLock<Subscribers<Client>>>,
 }
 
 impl<C> EthPubSubClient<C> {
@@ -60,6 +67,7 @@ impl<C> EthPubSubClient<C> {
         let heads_subscribers = Arc::new(RwLock::new(Subscribers::default()));
         let logs_subscribers = Arc::new(RwLock::new(Subscribers::default()));
         let transactions_subscribers = Arc::new(RwLock::new(Subscribers::default()));
+        let syncing_subscribers = Arc::new(RwLock::new(Subscribers::default()));
 
         EthPubSubClient {
             handler: Arc::new(ChainNotificationHandler {
@@ -68,10 +76,13 @@ impl<C> EthPubSubClient<C> {
                 heads_subscribers: heads_subscribers.clone(),
                 logs_subscribers: logs_subscribers.clone(),
                 transactions_subscribers: transactions_subscribers.clone(),
+                syncing_subscribers: syncing_subscribers.clone(),
+                sync_status: RwLock::new(SyncStatus::None),
             }),
             heads_subscribers,
             logs_subscribers,
             transactions_subscribers,
+            syncing_subscribers,
         }
     }
 
@@ -82,6 +93,7 @@ impl<C> EthPubSubClient<C> {
         *client.heads_subscribers.write() = Subscribers::new_test();
         *client.logs_subscribers.write() = Subscribers::new_test();
         *client.transactions_subscribers.write() = Subscribers::new_test();
+        *client.syncing_subscribers.write() = Subscribers::new_test();
         client
     }
 
@@ -91,6 +103,33 @@ impl<C> EthPubSubClient<C> {
     }
 }
 
+impl<C: BlockChainClient + 'static> EthPubSubClient<C> {
+    /// Starts polling sync status to notify `syncing` subscribers about its changes.
+    pub fn poll_sync_status<SN: ?Sized, S: ?Sized>(&self, snapshot: Arc<SN>, sync: Arc<S>)
+    where
+        SN: SnapshotService + 'static,
+        S: SyncProvider + 'static,
+    {
+        let handler = self.handler();
+        let timer = tokio_timer::wheel()
+            .tick_duration(Duration::from_millis(500))
+            .build();
+
+        let interval = timer.interval(Duration::from_millis(1000));
+        self.handler.executor.spawn(
+            interval
+                .map_err(|e| warn!("Polling timer error: {:?}", e))
+                .for_each(move |_| match handler.upgrade() {
+                    Some(handler) => {
+                        handler.notify_syncing(sync_status(&*handler.client, &*snapshot, &*sync));
+                        Ok(())
+                    }
+                    None => Err(()),
+                }),
+        );
+    }
+}
+
 /// PubSub Notification handler.
 pub struct ChainNotificationHandler<C> {
     client: Arc<C>,
@@ -98,6 +137,8 @@ pub struct ChainNotificationHandler<C> {
     heads_subscribers: Arc<RwLock<Subscribers<Client>>>,
     logs_subscribers: Arc<RwLock<Subscribers<(Client, EthFilter)>>>,
     transactions_subscribers: Arc<RwLock<Subscribers<Client>>>,
+    syncing_subscribers: Arc<RwLock<Subscribers<Client>>>,
+    sync_status: RwLock<SyncStatus>,
 }
 
 impl<C> ChainNotificationHandler<C> {
@@ -172,6 +213,25 @@ impl<C> ChainNotificationHandler<C> {
             }
         }
     }
+
+    /// Notify syncing subscribers about sync status if it changed since the last notification.
+    pub fn notify_syncing(&self, status: SyncStatus) {
+        {
+            let mut last_status = self.sync_status.write();
+            if *last_status == status {
+                return;
+            }
+            *last_status = status.clone();
+        }
+
+        for subscriber in self.syncing_subscribers.read().values() {
+            Self::notify(
+                &self.executor,
+                subscriber,
+                pubsub::Result::Syncing(status.clone()),
+            );
+        }
+    }
 }
 
 impl<C: BlockChainClient> ChainNotify for ChainNotificationHandler<C> {
@@ -261,7 +321,13 @@ impl<C: Send + Sync + 'static> EthPubSub for EthPubSubClient<C> {
             (pubsub::Kind::NewPendingTransactions, _) => {
                 errors::invalid_params("newPendingTransactions", "Expected no parameters.")
             }
-            _ => errors::unimplemented(None),
+            (pubsub::Kind::Syncing, None) => {
+                self.syncing_subscribers.write().push(subscriber);
+                return;
+            }
+            (pubsub::Kind::Syncing, _) => {
+                errors::invalid_params("syncing", "Expected no parameters.")
+            }
         };
 
         let _ = subscriber.reject(error);
@@ -271,7 +337,8 @@ impl<C: Send + Sync + 'static> EthPubSub for EthPubSubClient<C> {
         let res = self.heads_subscribers.write().remove(&id).is_some();
         let res2 = self.logs_subscribers.write().remove(&id).is_some();
         let res3 = self.transactions_subscribers.write().remove(&id).is_some();
+        let res4 = self.syncing_subscribers.write().remove(&id).is_some();
 
-        Ok(res || res2 || res3)
+        Ok(res || res2 || res3 || res4)
     }
 }
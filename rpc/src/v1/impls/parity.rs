@@ -15,11 +15,16 @@
 // along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Parity-specific rpc implementation.
-use std::{collections::BTreeMap, str::FromStr, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    net::IpAddr,
+    str::FromStr,
+    sync::Arc,
+};
 
 use crypto::DEFAULT_MAC;
 use ethcore::{
-    client::{BlockChainClient, Call, StateClient},
+    client::{BlockChainClient, Call, CallAnalytics, EngineInfo, StateClient, StateOrBlock},
     miner::{self, MinerService},
     snapshot::{RestorationStatus, SnapshotService},
     state::StateInfo,
@@ -31,7 +36,11 @@ use ethstore::random_phrase;
 use jsonrpc_core::{futures::future, BoxFuture, Result};
 use stats::PrometheusMetrics;
 use sync::{ManageNetwork, SyncProvider};
-use types::ids::BlockId;
+use types::{
+    header::Header,
+    ids::{BlockId, TransactionId},
+    transaction::{SignedTransaction, TypedTransaction},
+};
 use version::version_data;
 
 use v1::{
@@ -40,13 +49,14 @@ use v1::{
         block_import::is_major_importing,
         errors,
         external_signer::{SignerService, SigningQueue},
-        fake_sign, verify_signature, NetworkSettings,
+        fake_sign, verify_signature, FullDispatcher, NetworkSettings, NodeName,
     },
     metadata::Metadata,
     traits::Parity,
     types::{
-        block_number_to_id, BlockNumber, Bytes, CallRequest, ChainStatus, Histogram,
-        LocalTransactionStatus, Peers, Receipt, RecoveredAccount, RichHeader, RpcSettings,
+        block_number_to_id, AccessReport, BlockNumber, BlockReward, Bytes, CallRequest, CallResult,
+        ChainStatus, ConsensusCapability, Histogram, InternalTransaction, LocalTransactionStatus,
+        Peers, Receipt, RecoveredAccount, RichHeader, RpcSettings, SubmittedTransaction,
         Transaction, TransactionStats,
     },
 };
@@ -63,6 +73,7 @@ where
     net: Arc<dyn ManageNetwork>,
     logger: Arc<RotatingLogger>,
     settings: Arc<NetworkSettings>,
+    node_name: Arc<NodeName>,
     signer: Option<Arc<SignerService>>,
     ws_address: Option<Host>,
     snapshot: Option<Arc<dyn SnapshotService>>,
@@ -80,6 +91,7 @@ where
         net: Arc<dyn ManageNetwork>,
         logger: Arc<RotatingLogger>,
         settings: Arc<NetworkSettings>,
+        node_name: Arc<NodeName>,
         signer: Option<Arc<SignerService>>,
         ws_address: Option<Host>,
         snapshot: Option<Arc<dyn SnapshotService>>,
@@ -91,6 +103,7 @@ where
             net,
             logger,
             settings,
+            node_name,
             signer,
             ws_address,
             snapshot,
@@ -98,6 +111,47 @@ where
     }
 }
 
+impl<C, M, S> ParityClient<C, M>
+where
+    S: StateInfo + 'static,
+    C: BlockChainClient + PrometheusMetrics + StateClient<State = S>,
+    M: MinerService<State = S>,
+{
+    /// Returns state and header to execute calls against at given block.
+    fn state_and_header(&self, num: BlockNumber) -> Result<(S, Header)> {
+        if num == BlockNumber::Pending {
+            let info = self.client.chain_info();
+            let state = self
+                .miner
+                .pending_state(info.best_block_number)
+                .ok_or_else(errors::state_pruned)?;
+            let header = self
+                .miner
+                .pending_block_header(info.best_block_number)
+                .ok_or_else(errors::state_pruned)?;
+
+            Ok((state, header))
+        } else {
+            let id = match num {
+                BlockNumber::Num(num) => BlockId::Number(num),
+                BlockNumber::Earliest => BlockId::Earliest,
+                BlockNumber::Latest => BlockId::Latest,
+                BlockNumber::Pending => unreachable!(), // Already covered
+            };
+
+            let state = self.client.state_at(id).ok_or_else(errors::state_pruned)?;
+            let header = self
+                .client
+                .block_header(id)
+                .ok_or_else(errors::state_pruned)?
+                .decode()
+                .map_err(errors::decode)?;
+
+            Ok((state, header))
+        }
+    }
+}
+
 impl<C, M, S> Parity for ParityClient<C, M>
 where
     S: StateInfo + 'static,
@@ -106,6 +160,7 @@ where
         + PrometheusMetrics
         + StateClient<State = S>
         + Call<State = S>
+        + EngineInfo
         + 'static,
     M: MinerService<State = S> + 'static,
 {
@@ -142,7 +197,7 @@ where
     }
 
     fn net_chain(&self) -> Result<String> {
-        Ok(self.settings.chain.clone())
+        Ok(self.client.spec_name())
     }
 
     fn chain(&self) -> Result<String> {
@@ -167,8 +222,28 @@ where
         Ok(self.settings.network_port)
     }
 
+    fn listening_port(&self) -> Result<u16> {
+        self.net_port()
+    }
+
+    fn rpc_port(&self) -> Result<Option<u16>> {
+        Ok(if self.settings.rpc_enabled {
+            Some(self.settings.rpc_port)
+        } else {
+            None
+        })
+    }
+
+    fn ws_port(&self) -> Result<Option<u16>> {
+        Ok(if self.settings.ws_enabled {
+            Some(self.settings.ws_port)
+        } else {
+            None
+        })
+    }
+
     fn node_name(&self) -> Result<String> {
-        Ok(self.settings.name.clone())
+        Ok(self.node_name.get())
     }
 
     fn registry_address(&self) -> Result<Option<H160>> {
@@ -176,7 +251,8 @@ where
             .client
             .additional_params()
             .get("registrar")
-            .and_then(|s| Address::from_str(s).ok()))
+            .and_then(|s| Address::from_str(s).ok())
+            .filter(|address| !address.is_zero()))
     }
 
     fn rpc_settings(&self) -> Result<RpcSettings> {
@@ -208,6 +284,10 @@ where
         }
     }
 
+    fn signer_enabled(&self) -> Result<bool> {
+        Ok(self.signer.is_some())
+    }
+
     fn generate_secret_phrase(&self) -> Result<String> {
         Ok(random_phrase(12))
     }
@@ -262,6 +342,65 @@ where
             .map(|a| a.into_iter().map(Into::into).collect()))
     }
 
+    fn storage_ats(
+        &self,
+        address: H160,
+        positions: Vec<U256>,
+        block_number: Option<BlockNumber>,
+    ) -> Result<Vec<H256>> {
+        let positions: Vec<H256> = positions.into_iter().map(H256::from).collect();
+        let values = match block_number.unwrap_or_default() {
+            BlockNumber::Pending => {
+                let info = self.client.chain_info();
+                let state = self
+                    .miner
+                    .pending_state(info.best_block_number)
+                    .ok_or_else(errors::state_pruned)?;
+                self.client
+                    .storage_at_many(&address, &positions, state.into())
+            }
+            num => {
+                self.client
+                    .storage_at_many(&address, &positions, block_number_to_id(num).into())
+            }
+        };
+
+        values.ok_or_else(errors::state_pruned)
+    }
+
+    fn storage_at_strict(
+        &self,
+        address: H160,
+        position: U256,
+        block_number: Option<BlockNumber>,
+    ) -> Result<H256> {
+        let num = block_number.unwrap_or_default();
+        let state = || -> Result<StateOrBlock> {
+            match num.clone() {
+                BlockNumber::Pending => {
+                    let info = self.client.chain_info();
+                    self.miner
+                        .pending_state(info.best_block_number)
+                        .map(Into::into)
+                        .ok_or_else(errors::state_pruned)
+                }
+                num => Ok(block_number_to_id(num).into()),
+            }
+        };
+
+        let code = self
+            .client
+            .code(&address, state()?)
+            .ok_or_else(errors::state_pruned)?;
+        if code.map_or(true, |code| code.is_empty()) {
+            return Err(errors::not_a_contract(address));
+        }
+
+        self.client
+            .storage_at(&address, &H256::from(position), state()?)
+            .ok_or_else(errors::state_pruned)
+    }
+
     fn encrypt_message(&self, key: H512, phrase: Bytes) -> Result<Bytes> {
         ecies::encrypt(&key, &DEFAULT_MAC, &phrase.0)
             .map_err(errors::encryption)
@@ -281,6 +420,15 @@ where
             .collect())
     }
 
+    fn pending_transactions_by_address(&self, address: H160) -> Result<Vec<Transaction>> {
+        Ok(self
+            .miner
+            .queued_transactions_from_sender(&address)
+            .into_iter()
+            .map(|t| Transaction::from_pending(t.pending().clone()))
+            .collect())
+    }
+
     fn all_transactions(&self) -> Result<Vec<Transaction>> {
         let all_transactions = self.miner.queued_transactions();
 
@@ -290,6 +438,15 @@ where
             .collect())
     }
 
+    fn harvest_pending_transactions(&self) -> Result<Vec<Bytes>> {
+        Ok(self
+            .miner
+            .queued_transactions()
+            .into_iter()
+            .map(|t| Bytes::new(t.signed().encode()))
+            .collect())
+    }
+
     fn all_transaction_hashes(&self) -> Result<Vec<H256>> {
         Ok(self.miner.queued_transaction_hashes())
     }
@@ -306,6 +463,23 @@ where
             .collect())
     }
 
+    fn send_raw_transaction_detailed(&self, raw: Bytes) -> Result<SubmittedTransaction> {
+        let signed_transaction = TypedTransaction::decode(&raw.into_vec())
+            .map_err(errors::rlp)
+            .and_then(|tx| SignedTransaction::new(tx).map_err(errors::transaction))?;
+        let hash = FullDispatcher::dispatch_transaction(
+            &*self.client,
+            &*self.miner,
+            signed_transaction.into(),
+            false,
+        )?;
+
+        Ok(SubmittedTransaction {
+            hash,
+            queue_pressure: self.miner.queue_pressure(),
+        })
+    }
+
     fn local_transactions(&self) -> Result<BTreeMap<H256, LocalTransactionStatus>> {
         let transactions = self.miner.local_transactions();
         Ok(transactions
@@ -345,11 +519,27 @@ where
         Ok(ChainStatus { block_gap: gap })
     }
 
+    fn consensus_capability(&self) -> Result<ConsensusCapability> {
+        Ok(self.client.consensus_capability().into())
+    }
+
     fn node_kind(&self) -> Result<::v1::types::NodeKind> {
         use v1::types::{Availability, Capability, NodeKind};
 
+        // The node is public when its JSON-RPC server is reachable from outside the host.
+        let public = self.settings.rpc_enabled
+            && self
+                .settings
+                .rpc_interface
+                .parse::<IpAddr>()
+                .map_or(false, |ip| !ip.is_loopback());
+
         Ok(NodeKind {
-            availability: Availability::Personal,
+            availability: if public {
+                Availability::Public
+            } else {
+                Availability::Personal
+            },
             capability: Capability::Full,
         })
     }
@@ -412,48 +602,108 @@ where
         Box::new(future::ok(receipts.into_iter().map(Into::into).collect()))
     }
 
+    fn block_reward(&self, number: BlockNumber) -> Result<Option<BlockReward>> {
+        let id = match number {
+            BlockNumber::Pending => {
+                warn!("BlockNumber::Pending is unsupported");
+                return Ok(None);
+            }
+
+            num => block_number_to_id(num),
+        };
+
+        let block = match self.client.block(id) {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        let rewards = match self
+            .client
+            .engine()
+            .block_rewards(&block.decode_header(), &block.uncles())
+        {
+            Some(rewards) => rewards,
+            None => return Ok(None),
+        };
+
+        let gas_prices: HashMap<_, _> = block
+            .transactions()
+            .into_iter()
+            .map(|tx| (tx.hash(), tx.tx().gas_price))
+            .collect();
+        let transaction_fees = self
+            .client
+            .localized_block_receipts(BlockId::Hash(block.hash()))
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|receipt| {
+                gas_prices
+                    .get(&receipt.transaction_hash)
+                    .map(|gas_price| *gas_price * receipt.gas_used)
+            })
+            .fold(U256::zero(), |fees, fee| fees + fee);
+
+        Ok(Some(BlockReward {
+            total: rewards.base + rewards.uncle_inclusion + transaction_fees,
+            block_reward: rewards.base,
+            uncle_inclusion_reward: rewards.uncle_inclusion,
+            transaction_fees,
+            uncle_rewards: rewards.uncles,
+        }))
+    }
+
+    fn transaction_receipt(&self, hash: H256) -> BoxFuture<Option<Receipt>> {
+        let id = TransactionId::Hash(hash);
+        let receipt = self.client.transaction_receipt(id.clone()).map(|receipt| {
+            let mut receipt = Receipt::from(receipt);
+            receipt.internal_transactions = self
+                .client
+                .transaction_traces(id)
+                .map(|traces| InternalTransaction::from_traces(&traces));
+            receipt
+        });
+        Box::new(future::ok(receipt))
+    }
+
     fn call(&self, requests: Vec<CallRequest>, num: Option<BlockNumber>) -> Result<Vec<Bytes>> {
         let requests = requests
             .into_iter()
             .map(|request| Ok((fake_sign::sign_call(request.into())?, Default::default())))
             .collect::<Result<Vec<_>>>()?;
 
-        let num = num.unwrap_or_default();
+        let (mut state, header) = self.state_and_header(num.unwrap_or_default())?;
 
-        let (mut state, header) = if num == BlockNumber::Pending {
-            let info = self.client.chain_info();
-            let state = self
-                .miner
-                .pending_state(info.best_block_number)
-                .ok_or_else(errors::state_pruned)?;
-            let header = self
-                .miner
-                .pending_block_header(info.best_block_number)
-                .ok_or_else(errors::state_pruned)?;
+        self.client
+            .call_many(&requests, &mut state, &header)
+            .map(|res| res.into_iter().map(|res| res.output.into()).collect())
+            .map_err(errors::call)
+    }
 
-            (state, header)
-        } else {
-            let id = match num {
-                BlockNumber::Num(num) => BlockId::Number(num),
-                BlockNumber::Earliest => BlockId::Earliest,
-                BlockNumber::Latest => BlockId::Latest,
-                BlockNumber::Pending => unreachable!(), // Already covered
-            };
+    fn call_detailed(&self, request: CallRequest, num: Option<BlockNumber>) -> Result<CallResult> {
+        let signed = fake_sign::sign_call(request.into())?;
+        let (mut state, header) = self.state_and_header(num.unwrap_or_default())?;
 
-            let state = self.client.state_at(id).ok_or_else(errors::state_pruned)?;
-            let header = self
-                .client
-                .block_header(id)
-                .ok_or_else(errors::state_pruned)?
-                .decode()
-                .map_err(errors::decode)?;
+        self.client
+            .call(&signed, Default::default(), &mut state, &header)
+            .map(Into::into)
+            .map_err(errors::call)
+    }
 
-            (state, header)
+    fn call_with_access_report(
+        &self,
+        request: CallRequest,
+        num: Option<BlockNumber>,
+    ) -> Result<AccessReport> {
+        let signed = fake_sign::sign_call(request.into())?;
+        let (mut state, header) = self.state_and_header(num.unwrap_or_default())?;
+        let analytics = CallAnalytics {
+            state_diffing: true,
+            access_recording: true,
+            ..Default::default()
         };
 
         self.client
-            .call_many(&requests, &mut state, &header)
-            .map(|res| res.into_iter().map(|res| res.output.into()).collect())
+            .call(&signed, analytics, &mut state, &header)
+            .map(Into::into)
             .map_err(errors::call)
     }
 
@@ -25,16 +25,19 @@ use std::{
 
 use accounts::AccountProvider;
 use ethereum_types::{Address, H160, H256, H520};
-use ethkey::{Brain, Generator, Password, Secret};
+use ethkey::{Password, Secret};
 use ethstore::KeyFile;
-use jsonrpc_core::Result;
+use jsonrpc_core::{types::Value, Result};
 use v1::{
     helpers::{
         deprecated::{self, DeprecationNotice},
         errors,
     },
     traits::{ParityAccounts, ParityAccountsInfo},
-    types::{AccountInfo, Derive, DeriveHash, DeriveHierarchical, ExtAccountInfo},
+    types::{
+        AccountInfo, AccountKind, Derive, DeriveHash, DeriveHierarchical, ExtAccountInfo,
+        FullAccountInfo,
+    },
 };
 
 /// Account management (personal) rpc implementation.
@@ -61,15 +64,21 @@ impl ParityAccountsClient {
 }
 
 impl ParityAccountsInfo for ParityAccountsClient {
-    fn accounts_info(&self) -> Result<BTreeMap<H160, AccountInfo>> {
+    fn accounts_info(&self, vault: Option<String>) -> Result<BTreeMap<H160, AccountInfo>> {
         self.deprecation_notice("parity_accountsInfo");
 
-        let dapp_accounts = self
-            .accounts
-            .accounts()
-            .map_err(|e| errors::account("Could not fetch accounts.", e))?
-            .into_iter()
-            .collect::<HashSet<_>>();
+        let dapp_accounts = match vault {
+            Some(vault) => self
+                .accounts
+                .vault_accounts(&vault)
+                .map_err(|e| errors::account("Could not fetch vault accounts.", e))?,
+            None => self
+                .accounts
+                .accounts()
+                .map_err(|e| errors::account("Could not fetch accounts.", e))?,
+        }
+        .into_iter()
+        .collect::<HashSet<_>>();
 
         let info = self
             .accounts
@@ -137,11 +146,50 @@ impl ParityAccounts for ParityAccountsClient {
         Ok(accounts)
     }
 
+    fn get_all_accounts_info(&self) -> Result<BTreeMap<H160, FullAccountInfo>> {
+        self.deprecation_notice("parity_allAccountsInfo");
+
+        let info = self
+            .accounts
+            .accounts_info()
+            .map_err(|e| errors::account("Could not fetch account info.", e))?;
+        let other = self.accounts.addresses_info();
+
+        let keystore = info
+            .into_iter()
+            .map(|(address, v)| (address, v, AccountKind::Keystore));
+        let watch_only = other
+            .into_iter()
+            .map(|(address, v)| (address, v, AccountKind::WatchOnly));
+
+        let mut accounts = BTreeMap::new();
+        for (address, v, kind) in keystore.chain(watch_only) {
+            // Keystore entries take precedence over address book entries.
+            accounts
+                .entry(address.into())
+                .or_insert_with(|| FullAccountInfo {
+                    name: v.name,
+                    meta: v.meta,
+                    uuid: v.uuid.map(|uuid| uuid.to_string()),
+                    kind,
+                });
+        }
+
+        Ok(accounts)
+    }
+
+    fn set_default_account(&self, address: H160) -> Result<bool> {
+        self.deprecation_notice("parity_setDefaultAccount");
+        self.accounts
+            .set_default_account(address.into())
+            .map_err(|e| errors::account("Could not set default account.", e))
+            .map(|_| true)
+    }
+
     fn new_account_from_phrase(&self, phrase: String, pass: Password) -> Result<H160> {
         self.deprecation_notice("parity_newAccountFromPhrase");
-        let brain = Brain::new(phrase).generate().unwrap();
         self.accounts
-            .insert_account(brain.secret().clone(), &pass)
+            .insert_account_from_phrase(phrase, &pass)
             .map(Into::into)
             .map_err(|e| errors::account("Could not create account.", e))
     }
@@ -191,10 +239,11 @@ impl ParityAccounts for ParityAccountsClient {
     fn kill_account(&self, account: H160, password: Password) -> Result<bool> {
         self.deprecation_notice("parity_killAccount");
         let account: Address = account.into();
-        self.accounts
-            .kill_account(&account, &password)
-            .map(|_| true)
-            .map_err(|e| errors::account("Could not delete account.", e))
+        match self.accounts.kill_account(&account, &password) {
+            Ok(()) => Ok(true),
+            Err(ethstore::Error::InvalidPassword) => Ok(false),
+            Err(e) => Err(errors::account("Could not delete account.", e)),
+        }
     }
 
     fn remove_address(&self, addr: H160) -> Result<bool> {
@@ -277,6 +326,24 @@ impl ParityAccounts for ParityAccountsClient {
             .map(|_| true)
     }
 
+    fn set_vault_password(
+        &self,
+        name: String,
+        old_password: Password,
+        new_password: Password,
+    ) -> Result<bool> {
+        self.deprecation_notice("parity_setVaultPassword");
+
+        match self
+            .accounts
+            .set_vault_password(&name, &old_password, &new_password)
+        {
+            Ok(()) => Ok(true),
+            Err(ethstore::Error::InvalidPassword) => Ok(false),
+            Err(e) => Err(errors::account("Could not set vault password.", e)),
+        }
+    }
+
     fn change_vault(&self, address: H160, new_vault: String) -> Result<bool> {
         self.deprecation_notice("parity_changeVault");
         self.accounts
@@ -355,6 +422,14 @@ impl ParityAccounts for ParityAccountsClient {
             .map_err(|e| errors::account("Could not export account.", e))
     }
 
+    fn import_account(&self, keystore: Value, password: Password) -> Result<H160> {
+        self.deprecation_notice("parity_importAccount");
+        self.accounts
+            .import_wallet(keystore.to_string().as_bytes(), &password, false)
+            .map(Into::into)
+            .map_err(|e| errors::account("Could not import account.", e))
+    }
+
     fn sign_message(&self, addr: H160, password: Password, message: H256) -> Result<H520> {
         self.deprecation_notice("parity_signMessage");
         self.accounts
@@ -30,9 +30,14 @@ use sync::ManageNetwork;
 
 use jsonrpc_core::{futures::Future, BoxFuture, Result};
 use v1::{
-    helpers::errors,
+    helpers::{
+        dapps::{cid_to_url, DappsService, RecentDapps},
+        errors,
+        updater::UpdateService,
+        NodeName,
+    },
     traits::ParitySet,
-    types::{Bytes, Transaction},
+    types::{Bytes, LocalDapp, Transaction},
 };
 
 #[cfg(any(test, feature = "accounts"))]
@@ -85,6 +90,10 @@ pub struct ParitySetClient<C, M, F = fetch::Client> {
     client: Arc<C>,
     miner: Arc<M>,
     net: Arc<dyn ManageNetwork>,
+    dapps: Option<Arc<dyn DappsService>>,
+    recent_dapps: Arc<RecentDapps>,
+    updater: Option<Arc<dyn UpdateService>>,
+    node_name: Arc<NodeName>,
     fetch: F,
 }
 
@@ -93,11 +102,24 @@ where
     C: BlockChainClient + 'static,
 {
     /// Creates new `ParitySetClient` with given `Fetch`.
-    pub fn new(client: &Arc<C>, miner: &Arc<M>, net: &Arc<dyn ManageNetwork>, fetch: F) -> Self {
+    pub fn new(
+        client: &Arc<C>,
+        miner: &Arc<M>,
+        net: &Arc<dyn ManageNetwork>,
+        dapps: Option<Arc<dyn DappsService>>,
+        recent_dapps: &Arc<RecentDapps>,
+        updater: Option<Arc<dyn UpdateService>>,
+        node_name: &Arc<NodeName>,
+        fetch: F,
+    ) -> Self {
         ParitySetClient {
             client: client.clone(),
             miner: miner.clone(),
             net: net.clone(),
+            dapps,
+            recent_dapps: recent_dapps.clone(),
+            updater,
+            node_name: node_name.clone(),
             fetch,
         }
     }
@@ -126,6 +148,24 @@ where
         Ok(false)
     }
 
+    fn set_queue_lifetime(&self, seconds: u64) -> Result<bool> {
+        let lifetime = match seconds {
+            0 => None,
+            seconds => Some(Duration::from_secs(seconds)),
+        };
+        self.miner.set_queue_lifetime(lifetime);
+        Ok(true)
+    }
+
+    fn set_queue_pressure_threshold(&self, threshold: usize) -> Result<bool> {
+        let threshold = match threshold {
+            0 => None,
+            threshold => Some(threshold),
+        };
+        self.miner.set_queue_pressure_threshold(threshold);
+        Ok(true)
+    }
+
     fn set_gas_floor_target(&self, target: U256) -> Result<bool> {
         let mut range = self.miner.authoring_params().gas_range_target;
         range.0 = target;
@@ -145,6 +185,23 @@ where
         Ok(true)
     }
 
+    fn set_node_name(&self, name: String) -> Result<bool> {
+        if name.contains('/') {
+            return Err(errors::invalid_params(
+                "name",
+                "Node name must not contain '/'.",
+            ));
+        }
+        let default_extra_data = self
+            .node_name
+            .is_default_extra_data(&self.miner.authoring_params().extra_data);
+        self.node_name.set(name);
+        if default_extra_data {
+            self.miner.set_extra_data(self.node_name.extra_data());
+        }
+        Ok(true)
+    }
+
     fn set_author(&self, address: H160) -> Result<bool> {
         self.miner.set_author(miner::Author::External(address));
         Ok(true)
@@ -229,6 +286,37 @@ where
         Box::new(future)
     }
 
+    fn dapps_list(&self) -> Result<Vec<LocalDapp>> {
+        self.dapps
+            .as_ref()
+            .map(|dapps| dapps.list_dapps())
+            .ok_or_else(errors::dapps_disabled)
+    }
+
+    fn dapps_refresh(&self) -> Result<bool> {
+        self.dapps
+            .as_ref()
+            .map(|dapps| dapps.refresh_local_dapps())
+            .ok_or_else(errors::dapps_disabled)
+    }
+
+    fn cid_to_url(&self, cid: String) -> Result<String> {
+        let dapps = self.dapps.as_ref().ok_or_else(errors::dapps_disabled)?;
+        cid_to_url(&dapps.content_gateway(), &cid)
+            .ok_or_else(|| errors::invalid_params("cid", "Expected a CIDv0 or base32 CIDv1."))
+    }
+
+    fn list_recent_dapps(&self) -> Result<Vec<String>> {
+        Ok(self.recent_dapps.list())
+    }
+
+    fn upgrade_ready(&self) -> Result<bool> {
+        self.updater
+            .as_ref()
+            .map(|updater| updater.is_upgrade_ready())
+            .ok_or_else(errors::updater_disabled)
+    }
+
     fn remove_transaction(&self, hash: H256) -> Result<Option<Transaction>> {
         Ok(self
             .miner
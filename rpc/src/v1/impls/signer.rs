@@ -152,6 +152,98 @@ impl<D: Dispatcher + 'static> SignerClient<D> {
         )
     }
 
+    fn confirm_with_password(
+        &self,
+        id: U256,
+        modification: TransactionModification,
+        pass: String,
+    ) -> BoxFuture<ConfirmationResponse> {
+        Box::new(
+            self.confirm_internal(id, modification, move |dis, accounts, payload| {
+                dispatch::execute(
+                    dis,
+                    accounts,
+                    payload,
+                    dispatch::SignWith::Password(pass.into()),
+                )
+            })
+            .map(dispatch::WithToken::into_value),
+        )
+    }
+
+    fn confirm_raw(&self, id: U256, bytes: Bytes) -> Result<ConfirmationResponse> {
+        self.signer
+            .take(&id)
+            .map(|sender| {
+                let payload = sender.request.payload.clone();
+                let result = match payload {
+                    ConfirmationPayload::SendTransaction(request) => {
+                        Self::verify_transaction(bytes, request, |pending_transaction| {
+                            self.dispatcher
+                                .dispatch_transaction(pending_transaction)
+                                .map(Into::into)
+                                .map(ConfirmationResponse::SendTransaction)
+                        })
+                    }
+                    ConfirmationPayload::SignTransaction(request) => {
+                        Self::verify_transaction(bytes, request, |pending_transaction| {
+                            let rich = self.dispatcher.enrich(pending_transaction.transaction);
+                            Ok(ConfirmationResponse::SignTransaction(rich))
+                        })
+                    }
+                    ConfirmationPayload::EthSignMessage(address, data) => {
+                        let expected_hash = eth_data_hash(data);
+                        let signature = ethkey::Signature::from_electrum(&bytes.0);
+                        match ethkey::verify_address(&address, &signature, &expected_hash) {
+                            Ok(true) => {
+                                Ok(ConfirmationResponse::Signature(bytes.0.as_slice().into()))
+                            }
+                            Ok(false) => Err(errors::invalid_params(
+                                "Sender address does not match the signature.",
+                                (),
+                            )),
+                            Err(err) => {
+                                Err(errors::invalid_params("Invalid signature received.", err))
+                            }
+                        }
+                    }
+                    ConfirmationPayload::SignMessage(address, hash) => {
+                        let signature = ethkey::Signature::from_electrum(&bytes.0);
+                        match ethkey::verify_address(&address, &signature, &hash) {
+                            Ok(true) => {
+                                Ok(ConfirmationResponse::Signature(bytes.0.as_slice().into()))
+                            }
+                            Ok(false) => Err(errors::invalid_params(
+                                "Sender address does not match the signature.",
+                                (),
+                            )),
+                            Err(err) => {
+                                Err(errors::invalid_params("Invalid signature received.", err))
+                            }
+                        }
+                    }
+                    ConfirmationPayload::Decrypt(_address, _data) => {
+                        // TODO [ToDr]: Decrypt can we verify if the answer is correct?
+                        Ok(ConfirmationResponse::Decrypt(bytes))
+                    }
+                };
+                if let Ok(ref response) = result {
+                    self.signer.request_confirmed(sender, Ok(response.clone()));
+                } else {
+                    self.signer.request_untouched(sender);
+                }
+                result
+            })
+            .unwrap_or_else(|| Err(errors::invalid_params("Unknown RequestID", id)))
+    }
+
+    fn reject(&self, id: U256) -> bool {
+        self.signer
+            .take(&id)
+            .map(|sender| self.signer.request_rejected(sender))
+            .is_some()
+    }
+
     fn verify_transaction<F>(
         bytes: Bytes,
         request: FilledTransactionRequest,
@@ -223,17 +315,7 @@ impl<D: Dispatcher + 'static> Signer for SignerClient<D> {
         self.deprecation_notice
             .print("signer_confirmRequest", deprecated::msgs::ACCOUNTS);
 
-        Box::new(
-            self.confirm_internal(id, modification, move |dis, accounts, payload| {
-                dispatch::execute(
-                    dis,
-                    accounts,
-                    payload,
-                    dispatch::SignWith::Password(pass.into()),
-                )
-            })
-            .map(dispatch::WithToken::into_value),
-        )
+        self.confirm_with_password(id, modification, pass)
     }
 
     fn confirm_request_with_token(
@@ -268,80 +350,47 @@ impl<D: Dispatcher + 'static> Signer for SignerClient<D> {
         self.deprecation_notice
             .print("signer_confirmRequestRaw", deprecated::msgs::ACCOUNTS);
 
-        self.signer
-            .take(&id)
-            .map(|sender| {
-                let payload = sender.request.payload.clone();
-                let result = match payload {
-                    ConfirmationPayload::SendTransaction(request) => {
-                        Self::verify_transaction(bytes, request, |pending_transaction| {
-                            self.dispatcher
-                                .dispatch_transaction(pending_transaction)
-                                .map(Into::into)
-                                .map(ConfirmationResponse::SendTransaction)
-                        })
-                    }
-                    ConfirmationPayload::SignTransaction(request) => {
-                        Self::verify_transaction(bytes, request, |pending_transaction| {
-                            let rich = self.dispatcher.enrich(pending_transaction.transaction);
-                            Ok(ConfirmationResponse::SignTransaction(rich))
-                        })
-                    }
-                    ConfirmationPayload::EthSignMessage(address, data) => {
-                        let expected_hash = eth_data_hash(data);
-                        let signature = ethkey::Signature::from_electrum(&bytes.0);
-                        match ethkey::verify_address(&address, &signature, &expected_hash) {
-                            Ok(true) => {
-                                Ok(ConfirmationResponse::Signature(bytes.0.as_slice().into()))
-                            }
-                            Ok(false) => Err(errors::invalid_params(
-                                "Sender address does not match the signature.",
-                                (),
-                            )),
-                            Err(err) => {
-                                Err(errors::invalid_params("Invalid signature received.", err))
-                            }
-                        }
-                    }
-                    ConfirmationPayload::SignMessage(address, hash) => {
-                        let signature = ethkey::Signature::from_electrum(&bytes.0);
-                        match ethkey::verify_address(&address, &signature, &hash) {
-                            Ok(true) => {
-                                Ok(ConfirmationResponse::Signature(bytes.0.as_slice().into()))
-                            }
-                            Ok(false) => Err(errors::invalid_params(
-                                "Sender address does not match the signature.",
-                                (),
-                            )),
-                            Err(err) => {
-                                Err(errors::invalid_params("Invalid signature received.", err))
-                            }
-                        }
-                    }
-                    ConfirmationPayload::Decrypt(_address, _data) => {
-                        // TODO [ToDr]: Decrypt can we verify if the answer is correct?
-                        Ok(ConfirmationResponse::Decrypt(bytes))
-                    }
-                };
-                if let Ok(ref response) = result {
-                    self.signer.request_confirmed(sender, Ok(response.clone()));
-                } else {
-                    self.signer.request_untouched(sender);
-                }
-                result
-            })
-            .unwrap_or_else(|| Err(errors::invalid_params("Unknown RequestID", id)))
+        self.confirm_raw(id, bytes)
+    }
+
+    fn parity_requests_to_confirm(&self) -> Result<Vec<ConfirmationRequest>> {
+        self.deprecation_notice
+            .print("parity_signerRequestsToConfirm", deprecated::msgs::ACCOUNTS);
+
+        Ok(self.signer.requests().into_iter().map(Into::into).collect())
+    }
+
+    fn parity_confirm_request(
+        &self,
+        id: U256,
+        modification: TransactionModification,
+        pass: String,
+    ) -> BoxFuture<ConfirmationResponse> {
+        self.deprecation_notice
+            .print("parity_confirmRequest", deprecated::msgs::ACCOUNTS);
+
+        self.confirm_with_password(id, modification, pass)
+    }
+
+    fn parity_confirm_request_raw(&self, id: U256, bytes: Bytes) -> Result<ConfirmationResponse> {
+        self.deprecation_notice
+            .print("parity_confirmRequestRaw", deprecated::msgs::ACCOUNTS);
+
+        self.confirm_raw(id, bytes)
     }
 
     fn reject_request(&self, id: U256) -> Result<bool> {
         self.deprecation_notice
             .print("signer_rejectRequest", deprecated::msgs::ACCOUNTS);
 
-        let res = self
-            .signer
-            .take(&id)
-            .map(|sender| self.signer.request_rejected(sender));
-        Ok(res.is_some())
+        Ok(self.reject(id))
+    }
+
+    fn parity_reject_request(&self, id: U256) -> Result<bool> {
+        self.deprecation_notice
+            .print("parity_rejectRequest", deprecated::msgs::ACCOUNTS);
+
+        Ok(self.reject(id))
     }
 
     fn generate_token(&self) -> Result<String> {
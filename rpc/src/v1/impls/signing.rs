@@ -42,7 +42,8 @@ use v1::{
     types::{
         Bytes as RpcBytes, ConfirmationPayload as RpcConfirmationPayload,
         ConfirmationResponse as RpcConfirmationResponse, Either as RpcEither, Origin,
-        RichRawTransaction as RpcRichRawTransaction, TransactionRequest as RpcTransactionRequest,
+        RichRawTransaction as RpcRichRawTransaction, SentTransaction as RpcSentTransaction,
+        TransactionRequest as RpcTransactionRequest,
     },
 };
 
@@ -173,6 +174,36 @@ impl<D: Dispatcher + 'static> ParitySigning for SigningQueueClient<D> {
         )
     }
 
+    fn send_transaction_detailed(
+        &self,
+        _meta: Metadata,
+        request: RpcTransactionRequest,
+    ) -> BoxFuture<RpcSentTransaction> {
+        self.deprecation_notice
+            .print("parity_sendTransactionDetailed", deprecated::msgs::ACCOUNTS);
+        let accounts = self.accounts.clone();
+        let dispatcher = self.dispatcher.clone();
+        Box::new(
+            self.dispatcher
+                .fill_optional_fields(request.into(), accounts.default_account(), false)
+                .and_then(move |filled| {
+                    if accounts.is_unlocked(&filled.from) {
+                        Either::A(dispatch::send_detailed(
+                            dispatcher,
+                            &accounts,
+                            filled,
+                            dispatch::SignWith::Nothing,
+                        ))
+                    } else {
+                        Either::B(future::err(errors::unsupported(
+                            "Detailed sending requires an unlocked account.",
+                            Some("Use parity_postTransaction to confirm the transaction in the Trusted Signer."),
+                        )))
+                    }
+                }),
+        )
+    }
+
     fn post_sign(
         &self,
         meta: Metadata,
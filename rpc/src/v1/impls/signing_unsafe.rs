@@ -34,7 +34,8 @@ use v1::{
     types::{
         Bytes as RpcBytes, ConfirmationPayload as RpcConfirmationPayload,
         ConfirmationResponse as RpcConfirmationResponse, Either as RpcEither,
-        RichRawTransaction as RpcRichRawTransaction, TransactionRequest as RpcTransactionRequest,
+        RichRawTransaction as RpcRichRawTransaction, SentTransaction as RpcSentTransaction,
+        TransactionRequest as RpcTransactionRequest,
     },
 };
 
@@ -147,6 +148,29 @@ impl<D: Dispatcher + 'static> ParitySigning for SigningUnsafeClient<D> {
         )
     }
 
+    fn send_transaction_detailed(
+        &self,
+        _meta: Metadata,
+        request: RpcTransactionRequest,
+    ) -> BoxFuture<RpcSentTransaction> {
+        self.deprecation_notice
+            .print("parity_sendTransactionDetailed", deprecated::msgs::ACCOUNTS);
+        let accounts = self.accounts.clone();
+        let dispatcher = self.dispatcher.clone();
+        Box::new(
+            self.dispatcher
+                .fill_optional_fields(request.into(), accounts.default_account(), false)
+                .and_then(move |filled| {
+                    dispatch::send_detailed(
+                        dispatcher,
+                        &accounts,
+                        filled,
+                        dispatch::SignWith::Nothing,
+                    )
+                }),
+        )
+    }
+
     fn decrypt_message(&self, _: Metadata, address: H160, data: RpcBytes) -> BoxFuture<RpcBytes> {
         self.deprecation_notice
             .print("parity_decryptMessage", deprecated::msgs::ACCOUNTS);
@@ -40,6 +40,7 @@ fn to_call_analytics(flags: TraceOptions) -> CallAnalytics {
         transaction_tracing: flags.contains(&("trace".to_owned())),
         vm_tracing: flags.contains(&("vmTrace".to_owned())),
         state_diffing: flags.contains(&("stateDiff".to_owned())),
+        access_recording: false,
     }
 }
 
@@ -15,19 +15,31 @@
 // along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Web3 rpc implementation.
+use std::sync::Arc;
+
 use ethereum_types::H256;
 use hash::keccak;
 use jsonrpc_core::Result;
-use v1::{traits::Web3, types::Bytes};
-use version::version;
+use v1::{helpers::NodeName, traits::Web3, types::Bytes};
 
 /// Web3 rpc implementation.
 #[derive(Default)]
-pub struct Web3Client;
+pub struct Web3Client {
+    node_name: Arc<NodeName>,
+}
+
+impl Web3Client {
+    /// Creates new `Web3Client` reporting given node name in its client version.
+    pub fn new(node_name: &Arc<NodeName>) -> Self {
+        Web3Client {
+            node_name: node_name.clone(),
+        }
+    }
+}
 
 impl Web3 for Web3Client {
     fn client_version(&self) -> Result<String> {
-        Ok(version().to_owned().replacen("/", "//", 1))
+        Ok(self.node_name.client_version())
     }
 
     fn sha3(&self, data: Bytes) -> Result<H256> {
@@ -29,6 +29,7 @@ use std::{
     },
     time,
 };
+use v1::{helpers::dapps::RecentDapps, Metadata};
 
 pub use self::parity_runtime::Executor;
 
@@ -189,6 +190,9 @@ impl RpcStats {
 pub trait ActivityNotifier: Send + Sync + 'static {
     /// Activity on RPC interface
     fn active(&self);
+
+    /// Request made by given dapp; the id is client-supplied and must not be trusted
+    fn dapp_active(&self, _dapp_id: &str) {}
 }
 
 /// Stats-counting RPC middleware
@@ -204,23 +208,26 @@ impl<T: ActivityNotifier> Middleware<T> {
     }
 }
 
-impl<M: core::Metadata, T: ActivityNotifier> core::Middleware<M> for Middleware<T> {
+impl<T: ActivityNotifier> core::Middleware<Metadata> for Middleware<T> {
     type Future = core::FutureResponse;
     type CallFuture = core::middleware::NoopCallFuture;
 
     fn on_request<F, X>(
         &self,
         request: core::Request,
-        meta: M,
+        meta: Metadata,
         process: F,
     ) -> Either<Self::Future, X>
     where
-        F: FnOnce(core::Request, M) -> X,
+        F: FnOnce(core::Request, Metadata) -> X,
         X: core::futures::Future<Item = Option<core::Response>, Error = ()> + Send + 'static,
     {
         let start = time::Instant::now();
 
         self.notifier.active();
+        if let Some(ref dapp_id) = meta.dapp_id {
+            self.notifier.dapp_active(dapp_id);
+        }
         self.stats.count_request();
 
         let id = match request {
@@ -246,12 +253,18 @@ impl<M: core::Metadata, T: ActivityNotifier> core::Middleware<M> for Middleware<
 pub struct ClientNotifier {
     /// Client
     pub client: Arc<::ethcore::client::Client>,
+    /// Recently used dapps
+    pub recent_dapps: Arc<RecentDapps>,
 }
 
 impl ActivityNotifier for ClientNotifier {
     fn active(&self) {
         self.client.keep_alive()
     }
+
+    fn dapp_active(&self, dapp_id: &str) {
+        self.recent_dapps.note_used(dapp_id)
+    }
 }
 
 #[cfg(test)]
@@ -29,6 +29,9 @@ pub struct Metadata {
     pub origin: Origin,
     /// Request PubSub Session
     pub session: Option<Arc<Session>>,
+    /// Dapp making the request, taken from the HTTP `Origin` header.
+    /// Supplied by the client and not authenticated, so any caller can claim any id.
+    pub dapp_id: Option<String>,
 }
 
 impl jsonrpc_core::Metadata for Metadata {}
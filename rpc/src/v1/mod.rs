@@ -43,7 +43,7 @@ pub mod traits;
 
 pub use self::{
     extractors::{RpcExtractor, WsDispatcher, WsExtractor, WsStats},
-    helpers::{block_import, dispatch, NetworkSettings},
+    helpers::{block_import, dapps, dispatch, NetworkSettings, NodeName},
     impls::*,
     metadata::Metadata,
     traits::{
@@ -116,11 +116,15 @@ impl EthTester {
     }
 
     fn from_spec_conf(spec: Spec, config: ClientConfig) -> Self {
+        let miner_service = miner_service(&spec);
+        Self::from_spec_conf_miner(spec, config, miner_service)
+    }
+
+    fn from_spec_conf_miner(spec: Spec, config: ClientConfig, miner_service: Arc<Miner>) -> Self {
         let runtime = Runtime::with_thread_count(1);
         let account_provider = account_provider();
         let ap = account_provider.clone();
         let accounts = Arc::new(move || ap.accounts().unwrap_or_default()) as _;
-        let miner_service = miner_service(&spec);
         let snapshot_service = snapshot_service();
 
         let client = Client::new(
@@ -146,6 +150,12 @@ impl EthTester {
                 allow_experimental_rpcs: true,
                 allow_missing_blocks: false,
                 no_ancient_blocks: false,
+                logs_cache_size: 0,
+                logs_cache_confirmations: 64,
+                logs_bloom_index_size: 0,
+                receipt_revert_reason: false,
+                logs_max_blocks: 0,
+                logs_max_results: 0,
             },
         );
 
@@ -247,6 +257,169 @@ fn eth_get_proof() {
         .ends_with(res_new_acc.as_str()));
 }
 
+#[test]
+fn eth_get_proof_of_zero_address_and_precompile() {
+    let tester = EthTester::from_spec(
+        Spec::load(&env::temp_dir(), TRANSACTION_COUNT_SPEC).expect("invalid chain spec"),
+    );
+    let get_proof = |address: &str| {
+        let request = format!(
+            r#"{{"jsonrpc": "2.0", "method": "eth_getProof", "params": ["{}", ["0x0000000000000000000000000000000000000000000000000000000000000000"], "latest"], "id": 1}}"#,
+            address
+        );
+        let response = tester.handler.handle_request_sync(&request).unwrap();
+        let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+        response["result"].clone()
+    };
+
+    for address in &[
+        "0x0000000000000000000000000000000000000000",
+        "0x0000000000000000000000000000000000000004",
+    ] {
+        let proof = get_proof(address);
+        assert_eq!(proof["address"], *address);
+        assert_eq!(proof["balance"], "0x0");
+        assert_eq!(proof["nonce"], "0x0");
+        assert_eq!(
+            proof["codeHash"],
+            "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            proof["storageHash"],
+            "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+        );
+        // the proof of absence still walks the state trie from its root.
+        assert!(!proof["accountProof"].as_array().unwrap().is_empty());
+        assert_eq!(
+            proof["storageProof"][0]["value"], "0x0",
+            "Unexpected proof: {}",
+            proof
+        );
+    }
+}
+
+#[test]
+fn eth_call_with_zero_gas_price_from_empty_account() {
+    let chain = extract_chain!("BlockchainTests/ValidBlocks/bcWalletTest/wallet2outOf3txs");
+    let tester = EthTester::from_chain(&chain);
+
+    let req_call = r#"{
+		"jsonrpc": "2.0",
+		"method": "eth_call",
+		"params": [{
+			"from": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+			"to": "0xaaaf5374fce5edbc8e2a8697c15331677e6ebaaa",
+			"gas": "0x5208",
+			"gasPrice": "0x0",
+			"value": "0x0"
+		}, "latest"],
+		"id": 1
+	}"#;
+    let res_call = r#"{"jsonrpc":"2.0","result":"0x","id":1}"#.to_owned();
+    assert_eq!(
+        tester.handler.handle_request_sync(req_call).unwrap(),
+        res_call
+    );
+}
+
+#[test]
+fn eth_call_to_precompiles() {
+    let tester = EthTester::from_spec(
+        Spec::load(&env::temp_dir(), TRANSACTION_COUNT_SPEC).expect("invalid chain spec"),
+    );
+    let call = |to: &str, data: &str| {
+        let request = format!(
+            r#"{{"jsonrpc": "2.0", "method": "eth_call", "params": [{{"from":"0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa","to":"{}","gas":"0x10000","data":"{}"}}, "latest"], "id": 1}}"#,
+            to, data
+        );
+        tester.handler.handle_request_sync(&request).unwrap()
+    };
+
+    // identity echoes its input.
+    assert_eq!(
+        call("0x0000000000000000000000000000000000000004", "0x1234abcd"),
+        r#"{"jsonrpc":"2.0","result":"0x1234abcd","id":1}"#
+    );
+    // ecrecover yields no output for input which does not recover to a key.
+    assert_eq!(
+        call("0x0000000000000000000000000000000000000001", "0x1234"),
+        r#"{"jsonrpc":"2.0","result":"0x","id":1}"#
+    );
+}
+
+#[test]
+fn eth_estimate_gas_charges_cold_storage_access_after_berlin() {
+    // PUSH1 0 SLOAD POP STOP
+    let spec = |eip2929_transition: &str| {
+        let spec = String::from_utf8(TRANSACTION_COUNT_SPEC.to_vec())
+            .unwrap()
+            .replace(
+                r#""networkID" : "0x1""#,
+                &format!(
+                    r#""networkID" : "0x1", "eip2929Transition": "{}""#,
+                    eip2929_transition
+                ),
+            )
+            .replace(
+                r#""faa34835af5c2ea724333018a515fbb7d5bc0b33""#,
+                r#""0000000000000000000000000000000000000100": { "balance": "0", "code": "0x6000545000" },
+		"faa34835af5c2ea724333018a515fbb7d5bc0b33""#,
+            );
+        Spec::load(&env::temp_dir(), spec.as_bytes()).expect("invalid chain spec")
+    };
+    let estimate = |spec: Spec| {
+        let tester = EthTester::from_spec(spec);
+        let request = r#"{"jsonrpc": "2.0", "method": "eth_estimateGas", "params": [{"to":"0x0000000000000000000000000000000000000100"}, "latest"], "id": 1}"#;
+        let response = tester.handler.handle_request_sync(request).unwrap();
+        let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+        ::serde_json::from_value::<U256>(response["result"].clone()).unwrap()
+    };
+
+    let pre_berlin = estimate(spec("0xffffffffffffffff"));
+    let post_berlin = estimate(spec("0x0"));
+
+    // a cold SLOAD replaces the flat pre-Berlin SLOAD cost.
+    assert!(post_berlin > pre_berlin);
+    assert_eq!(
+        post_berlin - pre_berlin,
+        U256::from(::vm::schedule::EIP2929_COLD_SLOAD_COST - 50)
+    );
+}
+
+#[test]
+fn eth_transaction_and_receipt_report_same_sender() {
+    let chain = extract_chain!("BlockchainTests/ValidBlocks/bcWalletTest/wallet2outOf3txs");
+    let tester = EthTester::from_chain(&chain);
+    let request = |method: &str, params: &str| {
+        let request = format!(
+            r#"{{"jsonrpc": "2.0", "method": "{}", "params": [{}], "id": 1}}"#,
+            method, params
+        );
+        let response = tester.handler.handle_request_sync(&request).unwrap();
+        let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+        response["result"].clone()
+    };
+
+    let best_block = tester.client.chain_info().best_block_number;
+    let hashes: Vec<_> = (1..=best_block)
+        .flat_map(|number| {
+            let block = request(
+                "eth_getBlockByNumber",
+                &format!(r#""0x{:x}", false"#, number),
+            );
+            block["transactions"].as_array().unwrap().clone()
+        })
+        .collect();
+    assert!(!hashes.is_empty());
+    for hash in hashes {
+        let hash = format!("{}", hash);
+        let transaction = request("eth_getTransactionByHash", &hash);
+        let receipt = request("eth_getTransactionReceipt", &hash);
+        assert!(transaction["from"].is_string());
+        assert_eq!(transaction["from"], receipt["from"]);
+    }
+}
+
 #[test]
 fn eth_block_number() {
     let chain = extract_chain!("BlockchainTests/ValidBlocks/bcGasPricerTest/RPC_API_Test");
@@ -343,6 +516,46 @@ const TRANSACTION_COUNT_SPEC: &'static [u8] = br#"{
 }
 "#;
 
+const BASIC_AUTHORITY_SPEC: &'static [u8] = br#"{
+	"name": "BasicAuthority (Test)",
+	"engine": {
+		"basicAuthority": {
+			"params": {
+				"durationLimit": "0x0d",
+				"validators": {
+					"list": ["0x9cce34f7ab185c7aba1b7c8140d620b4bda941d6"]
+				}
+			}
+		}
+	},
+	"params": {
+		"gasLimitBoundDivisor": "0x0400",
+		"accountStartNonce": "0x00",
+		"maximumExtraDataSize": "0x20",
+		"minGasLimit": "0x1388",
+		"networkID" : "0x69"
+	},
+	"genesis": {
+		"seal": {
+			"generic": "0xc180"
+		},
+		"difficulty": "0x20000",
+		"author": "0x0000000000000000000000000000000000000000",
+		"timestamp": "0x00",
+		"parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+		"extraData": "0x",
+		"gasLimit": "0x2fefd8"
+	},
+	"accounts": {
+		"0000000000000000000000000000000000000001": { "builtin": { "name": "ecrecover", "pricing": { "linear": { "base": 3000, "word": 0 } } } },
+		"0000000000000000000000000000000000000002": { "builtin": { "name": "sha256", "pricing": { "linear": { "base": 60, "word": 12 } } } },
+		"0000000000000000000000000000000000000003": { "builtin": { "name": "ripemd160", "pricing": { "linear": { "base": 600, "word": 120 } } } },
+		"0000000000000000000000000000000000000004": { "builtin": { "name": "identity", "pricing": { "linear": { "base": 15, "word": 3 } } } },
+		"faa34835af5c2ea724333018a515fbb7d5bc0b33": { "balance": "10000000000000", "nonce": "0" }
+	}
+}
+"#;
+
 const POSITIVE_NONCE_SPEC: &'static [u8] = br#"{
 	"name": "Frontier (Test)",
 	"engine": {
@@ -484,6 +697,225 @@ fn eth_transaction_count() {
     );
 }
 
+#[test]
+fn eth_send_transaction_reports_balance_shortfall() {
+    let secret = "8a283037bb19c4fed7b1c569e40c7dcff366165eb869110a1b11532963eb9cb2"
+        .parse()
+        .unwrap();
+    let tester = EthTester::from_spec(
+        Spec::load(&env::temp_dir(), TRANSACTION_COUNT_SPEC).expect("invalid chain spec"),
+    );
+    let address = tester.accounts.insert_account(secret, &"".into()).unwrap();
+    tester
+        .accounts
+        .unlock_account_permanently(address, "".into())
+        .unwrap();
+
+    // sending the whole balance leaves nothing to pay 21000 gas at 1 wei.
+    let req_send_trans = r#"{
+		"jsonrpc": "2.0",
+		"method": "eth_sendTransaction",
+		"params": [{
+			"from": ""#
+        .to_owned()
+        + format!("0x{:x}", address).as_ref()
+        + r#"",
+			"to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+			"gas": "0x5208",
+			"gasPrice": "0x1",
+			"value": "0x9184e72a000"
+		}],
+		"id": 16
+	}"#;
+
+    let res_send_trans = r#"{"jsonrpc":"2.0","error":{"code":-32010,"message":"Insufficient funds. The account you tried to send transaction from does not have enough funds. Required 10000000021000 and got: 10000000000000, missing: 21000.","data":{"available":"0x9184e72a000","required":"0x9184e72f208","shortfall":"0x5208"}},"id":16}"#;
+
+    assert_eq!(
+        tester.handler.handle_request_sync(&req_send_trans).unwrap(),
+        res_send_trans
+    );
+}
+
+#[test]
+fn eth_send_raw_transaction_reports_minimal_gas_price() {
+    use ethcore::miner::{MinerOptions, MinerService};
+    use miner::{gas_pricer::GasPricer, pool::verifier};
+    use rustc_hex::ToHex;
+    use std::collections::HashSet;
+    use types::transaction::{Action, Transaction, TypedTransaction};
+
+    let secret = "8a283037bb19c4fed7b1c569e40c7dcff366165eb869110a1b11532963eb9cb2"
+        .parse()
+        .unwrap();
+    let spec = Spec::load(&env::temp_dir(), TRANSACTION_COUNT_SPEC).expect("invalid chain spec");
+    // raw transactions from unknown senders go through the external queue checks
+    let miner = Arc::new(Miner::new(
+        MinerOptions {
+            pool_verification_options: verifier::Options {
+                minimal_gas_price: 2.into(),
+                block_gas_limit: U256::max_value(),
+                tx_gas_limit: U256::max_value(),
+                no_early_reject: false,
+            },
+            tx_queue_no_unfamiliar_locals: true,
+            ..Default::default()
+        },
+        GasPricer::new_fixed(2.into()),
+        &spec,
+        HashSet::<Address>::new(),
+    ));
+    let tester = EthTester::from_spec_conf_miner(spec, ClientConfig::default(), miner.clone());
+    let address = tester.accounts.insert_account(secret, &"".into()).unwrap();
+    tester
+        .accounts
+        .unlock_account_permanently(address, "".into())
+        .unwrap();
+
+    let t = TypedTransaction::Legacy(Transaction {
+        nonce: U256::zero(),
+        gas_price: U256::one(),
+        gas: U256::from(0x5208),
+        action: Action::Call("d46e8dd67c5d32be8058bb8eb970870f07244567".parse().unwrap()),
+        value: U256::zero(),
+        data: vec![],
+    });
+    let signature = tester
+        .accounts
+        .sign(address, None, t.signature_hash(None))
+        .unwrap();
+    let rlp = t.with_signature(signature, None).encode().to_hex();
+
+    let req = r#"{
+		"jsonrpc": "2.0",
+		"method": "eth_sendRawTransaction",
+		"params": ["0x"#
+        .to_owned()
+        + &rlp
+        + r#""],
+		"id": 1
+	}"#;
+    let res = r#"{"jsonrpc":"2.0","error":{"code":-32010,"message":"Transaction gas price is too low. It does not satisfy your node's minimal gas price (minimal: 2, got: 1). Try increasing the gas price.","data":{"got":"0x1","minimal":"0x2"}},"id":1}"#;
+
+    assert_eq!(tester.handler.handle_request_sync(&req).unwrap(), res);
+    assert!(miner.queued_transactions().is_empty());
+}
+
+/// Sets up a basic authority chain with an engine signer set through `parity_setEngineSigner`
+/// and a plain value transfer sealed into block 1.
+/// Returns the tester, the authority and the hash of the transfer.
+fn authority_tester_with_sealed_transfer() -> (EthTester, Address, String) {
+    use v1::{impls::ParitySetAccountsClient, traits::ParitySetAccounts};
+
+    let tester = EthTester::from_spec(
+        Spec::load(&env::temp_dir(), BASIC_AUTHORITY_SPEC).expect("invalid chain spec"),
+    );
+    let authority = tester
+        .accounts
+        .insert_account(::hash::keccak("").into(), &"password".into())
+        .unwrap();
+    let sender = tester
+        .accounts
+        .insert_account(
+            "8a283037bb19c4fed7b1c569e40c7dcff366165eb869110a1b11532963eb9cb2"
+                .parse()
+                .unwrap(),
+            &"".into(),
+        )
+        .unwrap();
+    tester
+        .accounts
+        .unlock_account_permanently(sender, "".into())
+        .unwrap();
+
+    let mut parity_set = IoHandler::default();
+    parity_set
+        .extend_with(ParitySetAccountsClient::new(&tester.accounts, &tester._miner).to_delegate());
+    let request = format!(
+        r#"{{"jsonrpc": "2.0", "method": "parity_setEngineSigner", "params": ["{:?}", "password"], "id": 1}}"#,
+        authority
+    );
+    assert_eq!(
+        parity_set.handle_request_sync(&request).unwrap(),
+        r#"{"jsonrpc":"2.0","result":true,"id":1}"#
+    );
+
+    // a local transaction makes the engine seal a block on top of genesis.
+    let request = format!(
+        r#"{{"jsonrpc": "2.0", "method": "eth_sendTransaction", "params": [{{"from": "{:?}", "to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567", "gas": "0x5208", "gasPrice": "0x1", "value": "0x1"}}], "id": 1}}"#,
+        sender
+    );
+    let response = tester.handler.handle_request_sync(&request).unwrap();
+    let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+    let hash = response["result"].as_str().unwrap().to_owned();
+    assert_eq!(tester.client.chain_info().best_block_number, 1);
+
+    (tester, authority, hash)
+}
+
+#[test]
+fn parity_set_engine_signer_seals_blocks_as_authority() {
+    use ethcore::client::BlockInfo;
+    use ethereum_types::H520;
+    use ethkey::{public_to_address, recover};
+    use rustc_hex::FromHex;
+
+    let (tester, authority, _) = authority_tester_with_sealed_transfer();
+
+    let request = r#"{"jsonrpc": "2.0", "method": "eth_getBlockByNumber", "params": ["0x1", false], "id": 1}"#;
+    let response = tester.handler.handle_request_sync(request).unwrap();
+    let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+    let block = &response["result"];
+    assert_eq!(block["miner"], format!("{:?}", authority).as_str());
+
+    let seal: Vec<u8> = block["sealFields"][0].as_str().unwrap()[2..]
+        .from_hex()
+        .unwrap();
+    let signature: H520 = ::rlp::decode(&seal).unwrap();
+    let header = tester
+        .client
+        .block_header(BlockId::Number(1))
+        .unwrap()
+        .decode()
+        .unwrap();
+    let signer = public_to_address(&recover(&signature.into(), &header.bare_hash()).unwrap());
+    assert_eq!(signer, authority);
+}
+
+#[test]
+fn eth_get_logs_ignores_transactions_without_logs() {
+    // a plain value transfer emits no logs.
+    let (tester, _, hash) = authority_tester_with_sealed_transfer();
+
+    let request = format!(
+        r#"{{"jsonrpc": "2.0", "method": "eth_getTransactionReceipt", "params": ["{}"], "id": 1}}"#,
+        hash
+    );
+    let response = tester.handler.handle_request_sync(&request).unwrap();
+    let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+    assert_eq!(response["result"]["blockNumber"], "0x1");
+    assert!(response["result"]["logs"].as_array().unwrap().is_empty());
+
+    let block_hash = response["result"]["blockHash"].as_str().unwrap().to_owned();
+    let filters = vec![
+        r#"{"fromBlock": "0x1", "toBlock": "0x1"}"#.to_owned(),
+        r#"{"fromBlock": "earliest", "toBlock": "latest"}"#.to_owned(),
+        r#"{"fromBlock": "0x1", "toBlock": "0x1", "address": "0xd46e8dd67c5d32be8058bb8eb970870f07244567"}"#.to_owned(),
+        format!(r#"{{"blockHash": "{}"}}"#, block_hash),
+    ];
+    for filter in filters {
+        let request = format!(
+            r#"{{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{}], "id": 1}}"#,
+            filter
+        );
+        assert_eq!(
+            tester.handler.handle_request_sync(&request).unwrap(),
+            r#"{"jsonrpc":"2.0","result":[],"id":1}"#,
+            "filter {} should not match any logs",
+            filter
+        );
+    }
+}
+
 fn verify_transaction_counts(name: String, chain: BlockChain) {
     struct PanicHandler(String);
     impl Drop for PanicHandler {
@@ -0,0 +1,55 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of OpenEthereum.
+
+// OpenEthereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// OpenEthereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
+
+use parking_lot::Mutex;
+use v1::{helpers::dapps::DappsService, types::LocalDapp};
+
+/// Dapps server mock serving dapps from an in-memory directory.
+#[derive(Default)]
+pub struct TestDappsService {
+    /// Dapps present in the directory, picked up on refresh.
+    pub directory: Mutex<Vec<LocalDapp>>,
+    /// Dapps found during the last scan.
+    dapps: Mutex<Vec<LocalDapp>>,
+    /// Content gateway base URL.
+    pub gateway: String,
+}
+
+impl TestDappsService {
+    /// Creates a service which has already scanned given dapps.
+    pub fn new(dapps: Vec<LocalDapp>) -> Self {
+        TestDappsService {
+            directory: Mutex::new(dapps.clone()),
+            dapps: Mutex::new(dapps),
+            gateway: Default::default(),
+        }
+    }
+}
+
+impl DappsService for TestDappsService {
+    fn list_dapps(&self) -> Vec<LocalDapp> {
+        self.dapps.lock().clone()
+    }
+
+    fn refresh_local_dapps(&self) -> bool {
+        *self.dapps.lock() = self.directory.lock().clone();
+        true
+    }
+
+    fn content_gateway(&self) -> String {
+        self.gateway.clone()
+    }
+}
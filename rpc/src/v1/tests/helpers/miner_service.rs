@@ -19,14 +19,15 @@
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap},
     sync::Arc,
+    time::Duration,
 };
 
 use bytes::Bytes;
 use ethcore::{
     block::SealedBlock,
     client::{
-        test_client::TestState, traits::ForceUpdateSealing, EngineInfo, Nonce, PrepareOpenBlock,
-        StateClient,
+        test_client::TestState, traits::ForceUpdateSealing, BlockInfo, EngineInfo, Nonce,
+        PrepareOpenBlock, StateClient,
     },
     engines::{signer::EngineSigner, EthEngine},
     error::Error,
@@ -64,8 +65,17 @@ pub struct TestMinerService {
     pub min_gas_price: RwLock<Option<U256>>,
     /// Signer (if any)
     pub signer: RwLock<Option<Box<dyn EngineSigner>>>,
+    /// Maximal time a queued transaction is kept around.
+    pub queue_lifetime: RwLock<Option<Duration>>,
+    /// Maximal number of transactions in the queue.
+    pub queue_limit: RwLock<usize>,
+    /// Queue occupancy from which the queue is under pressure.
+    pub queue_pressure_threshold: RwLock<Option<usize>>,
 
     authoring_params: RwLock<AuthoringParams>,
+    queue_clock: RwLock<Duration>,
+    queued_at: Mutex<HashMap<H256, Duration>>,
+    queued_by_sender: Mutex<HashMap<Address, Vec<H256>>>,
 }
 
 impl Default for TestMinerService {
@@ -83,6 +93,12 @@ impl Default for TestMinerService {
                 extra_data: vec![1, 2, 3, 4],
             }),
             signer: RwLock::new(None),
+            queue_lifetime: RwLock::new(None),
+            queue_limit: RwLock::new(1_024),
+            queue_pressure_threshold: RwLock::new(None),
+            queue_clock: Default::default(),
+            queued_at: Default::default(),
+            queued_by_sender: Default::default(),
         }
     }
 }
@@ -94,6 +110,40 @@ impl TestMinerService {
         let nonce = next_nonces.entry(*address).or_insert_with(|| 0.into());
         *nonce = *nonce + 1;
     }
+
+    /// Puts the transaction into the queue, timestamped with the current queue clock.
+    pub fn queue_transaction(&self, tx: SignedTransaction) {
+        let hash = tx.hash();
+        self.queued_at.lock().insert(hash, *self.queue_clock.read());
+        self.queued_by_sender
+            .lock()
+            .entry(tx.sender())
+            .or_insert_with(Vec::new)
+            .push(hash);
+        self.pending_transactions.lock().insert(hash, tx);
+    }
+
+    /// Advances the queue clock and evicts transactions older than the queue lifetime.
+    pub fn tick(&self, elapsed: Duration) {
+        let now = {
+            let mut clock = self.queue_clock.write();
+            *clock += elapsed;
+            *clock
+        };
+        let lifetime = match *self.queue_lifetime.read() {
+            Some(lifetime) => lifetime,
+            None => return,
+        };
+
+        let mut pending = self.pending_transactions.lock();
+        self.queued_at.lock().retain(|hash, queued_at| {
+            let expired = now - *queued_at > lifetime;
+            if expired {
+                pending.remove(hash);
+            }
+            !expired
+        });
+    }
 }
 
 impl StateClient for TestMinerService {
@@ -201,16 +251,36 @@ impl MinerService for TestMinerService {
     }
 
     /// Called when blocks are imported to chain, updates transactions queue.
+    /// Only re-queues local transactions from retracted blocks.
     fn chain_new_blocks<C>(
         &self,
-        _chain: &C,
+        chain: &C,
         _imported: &[H256],
         _invalid: &[H256],
         _enacted: &[H256],
-        _retracted: &[H256],
+        retracted: &[H256],
         _is_internal: bool,
-    ) {
-        unimplemented!();
+    ) where
+        C: miner::BlockChainClient,
+    {
+        let mut local_transactions = self.local_transactions.lock();
+        for block in retracted
+            .iter()
+            .filter_map(|hash| chain.block(BlockId::Hash(*hash)))
+        {
+            for tx in block.transactions() {
+                let hash = tx.hash();
+                let mined = match local_transactions.get(&hash) {
+                    Some(&LocalTransactionStatus::Mined(ref tx)) => tx.clone(),
+                    _ => continue,
+                };
+
+                self.pending_transactions
+                    .lock()
+                    .insert(hash, mined.signed().clone());
+                local_transactions.insert(hash, LocalTransactionStatus::Pending(mined));
+            }
+        }
     }
 
     /// New chain head event. Restart mining operation.
@@ -289,6 +359,20 @@ impl MinerService for TestMinerService {
             .collect()
     }
 
+    fn queued_transactions_from_sender(&self, sender: &Address) -> Vec<Arc<VerifiedTransaction>> {
+        let pending = self.pending_transactions.lock();
+        self.queued_by_sender
+            .lock()
+            .get(sender)
+            .map_or_else(Vec::new, |hashes| {
+                hashes
+                    .iter()
+                    .filter_map(|hash| pending.get(hash).cloned())
+                    .map(|tx| Arc::new(VerifiedTransaction::from_pending_block_transaction(tx)))
+                    .collect()
+            })
+    }
+
     fn queued_transaction_hashes(&self) -> Vec<H256> {
         self.pending_transactions
             .lock()
@@ -324,11 +408,11 @@ impl MinerService for TestMinerService {
             },
             status: txpool::LightStatus {
                 mem_usage: 1_000,
-                transaction_count: 52,
+                transaction_count: self.imported_transactions.lock().len(),
                 senders: 1,
             },
             limits: txpool::Options {
-                max_count: 1_024,
+                max_count: *self.queue_limit.read(),
                 max_per_sender: 16,
                 max_mem_usage: 5_000,
             },
@@ -363,4 +447,16 @@ impl MinerService for TestMinerService {
             }
         }
     }
+
+    fn set_queue_lifetime(&self, lifetime: Option<Duration>) {
+        *self.queue_lifetime.write() = lifetime;
+    }
+
+    fn set_queue_pressure_threshold(&self, threshold: Option<usize>) {
+        *self.queue_pressure_threshold.write() = threshold;
+    }
+
+    fn queue_pressure_threshold(&self) -> Option<usize> {
+        *self.queue_pressure_threshold.read()
+    }
 }
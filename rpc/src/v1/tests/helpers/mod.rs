@@ -16,12 +16,16 @@
 
 //! Test rpc services.
 
+mod dapps;
 mod miner_service;
 mod snapshot_service;
 mod sync_provider;
+mod update_service;
 
 pub use self::{
+    dapps::TestDappsService,
     miner_service::TestMinerService,
     snapshot_service::TestSnapshotService,
     sync_provider::{Config, TestSyncProvider},
+    update_service::TestUpdater,
 };
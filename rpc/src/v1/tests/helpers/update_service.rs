@@ -0,0 +1,32 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of OpenEthereum.
+
+// OpenEthereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// OpenEthereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use v1::helpers::updater::UpdateService;
+
+/// Updater mock with a settable staged release.
+#[derive(Default)]
+pub struct TestUpdater {
+    /// Whether a release is staged.
+    pub ready: AtomicBool,
+}
+
+impl UpdateService for TestUpdater {
+    fn is_upgrade_ready(&self) -> bool {
+        self.ready.load(Ordering::SeqCst)
+    }
+}
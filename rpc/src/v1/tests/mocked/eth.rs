@@ -17,16 +17,21 @@
 use std::{
     collections::HashMap,
     str::FromStr,
-    sync::Arc,
+    sync::{atomic::Ordering, Arc, Weak},
     time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use accounts::AccountProvider;
 use ethcore::{
-    client::{BlockChainClient, EachBlockWith, Executed, TestBlockChainClient},
+    client::{
+        BlockChainClient, ChainNotify, ChainRoute, EachBlockWith, Executed, NewBlocks,
+        TestBlockChainClient,
+    },
     miner::{self, MinerService},
 };
-use ethereum_types::{Address, Bloom, H160, H256, U256};
+use ethereum_types::{Address, Bloom, BloomInput, H160, H256, U256};
+use ethkey::{Generator, Random};
+use hash::{keccak, KECCAK_EMPTY};
 use miner::external::ExternalMiner;
 use parity_runtime::Runtime;
 use parking_lot::Mutex;
@@ -41,6 +46,7 @@ use types::{
 
 use jsonrpc_core::IoHandler;
 use v1::{
+    helpers::BloomIndexHandler,
     metadata::Metadata,
     tests::helpers::{Config, TestMinerService, TestSnapshotService, TestSyncProvider},
     Eth, EthClient, EthClientOptions, EthFilter, EthFilterClient,
@@ -78,6 +84,7 @@ struct EthTester {
     pub miner: Arc<TestMinerService>,
     pub snapshot: Arc<TestSnapshotService>,
     hashrates: Arc<Mutex<HashMap<H256, (Instant, U256)>>>,
+    bloom_index: Weak<BloomIndexHandler<TestBlockChainClient>>,
     pub io: IoHandler<Metadata>,
 }
 
@@ -89,8 +96,11 @@ impl Default for EthTester {
 
 impl EthTester {
     pub fn new_with_options(options: EthClientOptions) -> Self {
+        Self::new_with_client(blockchain_client(), options)
+    }
+
+    pub fn new_with_client(client: Arc<TestBlockChainClient>, options: EthClientOptions) -> Self {
         let runtime = Runtime::with_thread_count(1);
-        let client = blockchain_client();
         let sync = sync_provider();
         let ap = accounts_provider();
         let ap2 = ap.clone();
@@ -107,8 +117,9 @@ impl EthTester {
             &miner,
             &external_miner,
             options,
-        )
-        .to_delegate();
+        );
+        let bloom_index = eth.bloom_index_handler();
+        let eth = eth.to_delegate();
         let filter = EthFilterClient::new(client.clone(), miner.clone(), 60).to_delegate();
 
         let mut io: IoHandler<Metadata> = IoHandler::default();
@@ -124,6 +135,21 @@ impl EthTester {
             snapshot,
             io,
             hashrates,
+            bloom_index,
+        }
+    }
+
+    pub fn notify_new_blocks(&self) {
+        if let Some(handler) = self.bloom_index.upgrade() {
+            handler.new_blocks(NewBlocks::new(
+                vec![],
+                vec![],
+                ChainRoute::new(vec![]),
+                vec![],
+                vec![],
+                Duration::default(),
+                false,
+            ));
         }
     }
 
@@ -300,6 +326,420 @@ fn rpc_eth_logs() {
     );
 }
 
+#[test]
+fn rpc_eth_logs_filtered_by_address_over_latest() {
+    let tester = EthTester::default();
+    let log = |address: u64| LocalizedLogEntry {
+        block_number: 1,
+        block_hash: H256::default(),
+        entry: LogEntry {
+            address: Address::from(address),
+            topics: vec![],
+            data: vec![],
+        },
+        transaction_index: 0,
+        transaction_log_index: 0,
+        transaction_hash: H256::default(),
+        log_index: 0,
+    };
+    tester.client.set_logs(vec![log(1), log(2)]);
+
+    let request = r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{"address":"0x0000000000000000000000000000000000000002"}], "id": 1}"#;
+    let response = tester.io.handle_request_sync(request).unwrap();
+    let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+    let logs = response["result"].as_array().unwrap();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(
+        logs[0]["address"],
+        "0x0000000000000000000000000000000000000002"
+    );
+}
+
+#[test]
+fn rpc_eth_logs_cached_for_finalized_range() {
+    let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+        options.logs_cache_size = 16;
+        options.logs_cache_confirmations = 2;
+    }));
+    tester.client.add_blocks(10, EachBlockWith::Nothing);
+    tester.client.set_logs(vec![LocalizedLogEntry {
+        block_number: 1,
+        block_hash: H256::default(),
+        entry: LogEntry {
+            address: Address::default(),
+            topics: vec![],
+            data: vec![1, 2, 3],
+        },
+        transaction_index: 0,
+        transaction_log_index: 0,
+        transaction_hash: H256::default(),
+        log_index: 0,
+    }]);
+
+    let finalized = r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{"fromBlock":"0x1","toBlock":"0x2"}], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":[{"address":"0x0000000000000000000000000000000000000000","blockHash":"0x0000000000000000000000000000000000000000000000000000000000000000","blockNumber":"0x1","data":"0x010203","logIndex":"0x0","removed":false,"topics":[],"transactionHash":"0x0000000000000000000000000000000000000000000000000000000000000000","transactionIndex":"0x0","transactionLogIndex":"0x0","type":"mined"}],"id":1}"#;
+
+    assert_eq!(
+        tester.io.handle_request_sync(finalized),
+        Some(response.to_owned())
+    );
+    assert_eq!(
+        tester.io.handle_request_sync(finalized),
+        Some(response.to_owned())
+    );
+    assert_eq!(tester.client.logs_queries.load(Ordering::Relaxed), 1);
+
+    // ranges reaching past the finalized block are always queried
+    let recent = r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{"fromBlock":"0x1","toBlock":"latest"}], "id": 1}"#;
+    tester.io.handle_request_sync(recent);
+    tester.io.handle_request_sync(recent);
+    assert_eq!(tester.client.logs_queries.load(Ordering::Relaxed), 3);
+
+    // a reorg replacing the last block of the range bypasses the cached response
+    tester.client.numbers.write().insert(2, H256::from(0xdead));
+    tester.io.handle_request_sync(finalized);
+    assert_eq!(tester.client.logs_queries.load(Ordering::Relaxed), 4);
+}
+
+#[test]
+fn rpc_eth_logs_use_bloom_index() {
+    let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+        options.logs_bloom_index_size = 16;
+    }));
+    let address = Address::from(1);
+    tester.client.add_blocks(2, EachBlockWith::Nothing);
+    tester
+        .client
+        .add_block(EachBlockWith::Nothing, |mut header| {
+            header.set_log_bloom(Bloom::from(BloomInput::Raw(&address)));
+            header
+        });
+    tester.client.add_blocks(2, EachBlockWith::Nothing);
+
+    let log = |block_number, address| LocalizedLogEntry {
+        block_number,
+        block_hash: H256::default(),
+        entry: LogEntry {
+            address,
+            topics: vec![],
+            data: vec![1, 2, 3],
+        },
+        transaction_index: 0,
+        transaction_log_index: 0,
+        transaction_hash: H256::default(),
+        log_index: 0,
+    };
+    tester
+        .client
+        .set_logs(vec![log(2, Address::from(2)), log(3, address)]);
+    tester.notify_new_blocks();
+
+    let request = r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{"fromBlock":"0x1","toBlock":"0x5","address":"0x0000000000000000000000000000000000000001"}], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":[{"address":"0x0000000000000000000000000000000000000001","blockHash":"0x0000000000000000000000000000000000000000000000000000000000000000","blockNumber":"0x3","data":"0x010203","logIndex":"0x0","removed":false,"topics":[],"transactionHash":"0x0000000000000000000000000000000000000000000000000000000000000000","transactionIndex":"0x0","transactionLogIndex":"0x0","type":"mined"}],"id":1}"#;
+
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+    // only the block whose bloom matches is queried
+    assert_eq!(tester.client.logs_queries.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn rpc_eth_logs_empty_topics_match_any() {
+    let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+        options.logs_bloom_index_size = 16;
+    }));
+    let topic = H256::from(7);
+    tester.client.add_blocks(1, EachBlockWith::Nothing);
+    tester
+        .client
+        .add_block(EachBlockWith::Nothing, |mut header| {
+            let mut bloom = Bloom::from(BloomInput::Raw(&Address::from(2)));
+            bloom.accrue(BloomInput::Raw(&topic));
+            header.set_log_bloom(bloom);
+            header
+        });
+
+    let log = |block_number, address, topics| LocalizedLogEntry {
+        block_number,
+        block_hash: H256::from(block_number),
+        entry: LogEntry {
+            address,
+            topics,
+            data: vec![],
+        },
+        transaction_index: 0,
+        transaction_log_index: 0,
+        transaction_hash: H256::default(),
+        log_index: 0,
+    };
+    tester.client.set_logs(vec![
+        log(1, Address::from(1), vec![]),
+        log(2, Address::from(2), vec![topic]),
+    ]);
+    tester.notify_new_blocks();
+
+    let logs = |topics: &str| {
+        let request = format!(
+            r#"{{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{{"fromBlock":"0x1","toBlock":"0x2"{}}}], "id": 1}}"#,
+            topics
+        );
+        tester.io.handle_request_sync(&request).unwrap()
+    };
+
+    let omitted = logs("");
+    let response: ::serde_json::Value = ::serde_json::from_str(&omitted).unwrap();
+    assert_eq!(response["result"].as_array().unwrap().len(), 2);
+    assert_eq!(logs(r#","topics":[]"#), omitted);
+    assert_eq!(logs(r#","topics":[[]]"#), omitted);
+}
+
+#[test]
+fn rpc_eth_logs_exact_and_any_of_topics() {
+    let tester = EthTester::default();
+    let event = H256::from(1);
+    let log = |log_index, topics| LocalizedLogEntry {
+        block_number: 0,
+        block_hash: H256::default(),
+        entry: LogEntry {
+            address: Address::default(),
+            topics,
+            data: vec![],
+        },
+        transaction_index: 0,
+        transaction_log_index: log_index,
+        transaction_hash: H256::default(),
+        log_index,
+    };
+    tester.client.set_logs(vec![
+        log(0, vec![event, H256::from(10)]),
+        log(1, vec![event, H256::from(11)]),
+        log(2, vec![event, H256::from(12)]),
+        log(3, vec![H256::from(2), H256::from(10)]),
+        log(4, vec![event]),
+    ]);
+
+    let request = format!(
+        r#"{{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{{"fromBlock":"0x0","toBlock":"0x0","topics":["{:?}",["{:?}","{:?}"]]}}], "id": 1}}"#,
+        event,
+        H256::from(10),
+        H256::from(11)
+    );
+    let response = tester.io.handle_request_sync(&request).unwrap();
+    let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+    let matched: Vec<_> = response["result"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|log| log["logIndex"].as_str().unwrap().to_owned())
+        .collect();
+    assert_eq!(matched, vec!["0x0", "0x1"]);
+}
+
+#[test]
+fn rpc_eth_logs_deduplicated_across_overlapping_scans() {
+    let tester = EthTester::default();
+    let log = |block_number, log_index| LocalizedLogEntry {
+        block_number,
+        block_hash: H256::from(block_number),
+        entry: LogEntry {
+            address: Address::default(),
+            topics: vec![],
+            data: vec![],
+        },
+        transaction_index: 0,
+        transaction_log_index: 0,
+        transaction_hash: H256::default(),
+        log_index,
+    };
+    // block 2 is returned by both scans that cover it
+    tester
+        .client
+        .set_logs(vec![log(1, 0), log(2, 0), log(2, 0), log(2, 1)]);
+
+    let keys = |request: &str| {
+        let response = tester.io.handle_request_sync(request).unwrap();
+        let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+        response["result"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|log| {
+                (
+                    log["blockHash"].as_str().unwrap().to_owned(),
+                    log["logIndex"].as_str().unwrap().to_owned(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let expected = vec![
+        (format!("{:?}", H256::from(1)), "0x0".to_owned()),
+        (format!("{:?}", H256::from(2)), "0x0".to_owned()),
+        (format!("{:?}", H256::from(2)), "0x1".to_owned()),
+    ];
+
+    assert_eq!(
+        keys(r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{}], "id": 1}"#),
+        expected
+    );
+
+    tester.io.handle_request_sync(
+        r#"{"jsonrpc": "2.0", "method": "eth_newFilter", "params": [{}], "id": 1}"#,
+    );
+    assert_eq!(
+        keys(r#"{"jsonrpc": "2.0", "method": "eth_getFilterChanges", "params": ["0x0"], "id": 1}"#),
+        expected
+    );
+    assert_eq!(
+        keys(r#"{"jsonrpc": "2.0", "method": "eth_getFilterLogs", "params": ["0x0"], "id": 1}"#),
+        expected
+    );
+}
+
+#[test]
+fn rpc_eth_logs_too_many_blocks() {
+    let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+        options.logs_max_blocks = 3;
+        options.logs_max_results = 1;
+    }));
+    tester.client.add_blocks(5, EachBlockWith::Nothing);
+
+    let request = r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{"fromBlock":"0x1","toBlock":"0x4"}], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32041,"message":"Too many blocks: query spans 4 blocks, the limit is 3"},"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+
+    let request = r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{"fromBlock":"0x2","toBlock":"0x4"}], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":[],"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+}
+
+#[test]
+fn rpc_eth_logs_too_many_results() {
+    let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+        options.logs_max_blocks = 3;
+        options.logs_max_results = 1;
+    }));
+    let log = |log_index| LocalizedLogEntry {
+        block_number: 0,
+        block_hash: H256::default(),
+        entry: LogEntry {
+            address: Address::default(),
+            topics: vec![],
+            data: vec![],
+        },
+        transaction_index: 0,
+        transaction_log_index: log_index,
+        transaction_hash: H256::default(),
+        log_index,
+    };
+    tester.client.set_logs(vec![log(0), log(1)]);
+
+    let request = r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{}], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32041,"message":"Too many results: query matches more than 1 logs"},"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+
+    // results within the cap after applying the filter limit are fine
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{"limit":1}], "id": 1}"#;
+    let response = tester.io.handle_request_sync(request).unwrap();
+    let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+    assert_eq!(response["result"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn rpc_eth_logs_over_the_cap_are_not_cached() {
+    let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+        options.logs_cache_size = 16;
+        options.logs_cache_confirmations = 2;
+        options.logs_max_results = 1;
+    }));
+    tester.client.add_blocks(10, EachBlockWith::Nothing);
+    let log = |log_index| LocalizedLogEntry {
+        block_number: 1,
+        block_hash: H256::default(),
+        entry: LogEntry {
+            address: Address::default(),
+            topics: vec![],
+            data: vec![],
+        },
+        transaction_index: 0,
+        transaction_log_index: log_index,
+        transaction_hash: H256::default(),
+        log_index,
+    };
+    tester.client.set_logs(vec![log(0), log(1), log(2)]);
+
+    let request = r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{"fromBlock":"0x1","toBlock":"0x2"}], "id": 1}"#;
+    let too_many = r#"{"jsonrpc":"2.0","error":{"code":-32041,"message":"Too many results: query matches more than 1 logs"},"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(too_many.to_owned())
+    );
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(too_many.to_owned())
+    );
+    // nothing was cached, so both requests reached the client
+    assert_eq!(tester.client.logs_queries.load(Ordering::Relaxed), 2);
+
+    // a result within the cap is cached as usual
+    tester.client.set_logs(vec![log(0)]);
+    tester.io.handle_request_sync(request);
+    tester.io.handle_request_sync(request);
+    assert_eq!(tester.client.logs_queries.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn rpc_eth_logs_cap_stops_bloom_index_walk() {
+    let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+        options.logs_bloom_index_size = 16;
+        options.logs_max_results = 1;
+    }));
+    let address = Address::from(1);
+    let log = |block_number| LocalizedLogEntry {
+        block_number,
+        block_hash: H256::from(block_number),
+        entry: LogEntry {
+            address,
+            topics: vec![],
+            data: vec![],
+        },
+        transaction_index: 0,
+        transaction_log_index: 0,
+        transaction_hash: H256::default(),
+        log_index: 0,
+    };
+    for _ in 0..4 {
+        tester
+            .client
+            .add_block(EachBlockWith::Nothing, |mut header| {
+                header.set_log_bloom(Bloom::from(BloomInput::Raw(&address)));
+                header
+            });
+    }
+    tester.client.set_logs(vec![log(1), log(2), log(3), log(4)]);
+    tester.notify_new_blocks();
+
+    let request = r#"{"jsonrpc": "2.0", "method": "eth_getLogs", "params": [{"fromBlock":"0x1","toBlock":"0x4","address":"0x0000000000000000000000000000000000000001"}], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32041,"message":"Too many results: query matches more than 1 logs"},"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+    // only the two newest candidate blocks were needed to exceed the cap
+    assert_eq!(tester.client.logs_queries.load(Ordering::Relaxed), 2);
+}
+
 #[test]
 fn rpc_eth_logs_error() {
     let tester = EthTester::default();
@@ -552,6 +992,25 @@ fn rpc_eth_accounts() {
     );
 }
 
+#[test]
+fn rpc_eth_accounts_default_first() {
+    let tester = EthTester::default();
+    tester.accounts_provider.new_account(&"".into()).unwrap();
+    tester.accounts_provider.new_account(&"".into()).unwrap();
+    let accounts = tester.accounts_provider.accounts().unwrap();
+    tester
+        .accounts_provider
+        .set_default_account(accounts[1])
+        .unwrap();
+
+    let request = r#"{"jsonrpc": "2.0", "method": "eth_accounts", "params": [], "id": 1}"#;
+    let response = format!(
+        r#"{{"jsonrpc":"2.0","result":["0x{:x}","0x{:x}"],"id":1}}"#,
+        accounts[1], accounts[0]
+    );
+    assert_eq!(tester.io.handle_request_sync(request), Some(response));
+}
+
 #[test]
 fn rpc_eth_block_number() {
     let tester = EthTester::default();
@@ -566,6 +1025,19 @@ fn rpc_eth_block_number() {
     );
 }
 
+#[test]
+fn rpc_eth_block_number_on_fresh_client() {
+    let tester = EthTester::default();
+
+    let request = r#"{"jsonrpc": "2.0", "method": "eth_blockNumber", "params": [], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"0x0","id":1}"#;
+
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+}
+
 #[test]
 fn rpc_eth_balance() {
     let tester = EthTester::default();
@@ -605,6 +1077,26 @@ fn rpc_eth_balance_pending() {
     );
 }
 
+#[test]
+fn rpc_eth_balance_future_block() {
+    let tester = EthTester::default();
+    tester.client.add_blocks(10, EachBlockWith::Nothing);
+    tester.client.set_balance(Address::from(1), U256::from(5));
+
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "eth_getBalance",
+		"params": ["0x0000000000000000000000000000000000000001", "0x64"],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"Block #100 not found: it is beyond the current head #10"},"id":1}"#;
+
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+}
+
 #[test]
 fn rpc_eth_storage_at() {
     let tester = EthTester::default();
@@ -626,6 +1118,58 @@ fn rpc_eth_storage_at() {
     );
 }
 
+#[test]
+fn rpc_eth_storage_at_of_account_without_code() {
+    let tester = EthTester::default();
+
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "eth_getStorageAt",
+		"params": ["0x0000000000000000000000000000000000000002", "0x4", "latest"],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"0x0000000000000000000000000000000000000000000000000000000000000000","id":1}"#;
+
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+}
+
+#[test]
+fn rpc_eth_proof_code_hash() {
+    let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+        options.allow_experimental_rpcs = true;
+    }));
+    let code = vec![0x60, 0x00, 0x60, 0x00, 0xf3];
+    tester.client.set_balance(Address::from(1), 5.into());
+    tester.client.set_code(Address::from(2), code.clone());
+
+    let code_hash = |address: &str| {
+        let request = r#"{
+			"jsonrpc": "2.0",
+			"method": "eth_getProof",
+			"params": [""#
+            .to_owned()
+            + address
+            + r#"", [], "latest"],
+			"id": 1
+		}"#;
+        let response = tester.io.handle_request_sync(&request).unwrap();
+        let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+        response["result"]["codeHash"].as_str().unwrap().to_owned()
+    };
+
+    assert_eq!(
+        code_hash("0x0000000000000000000000000000000000000001"),
+        format!("0x{:x}", KECCAK_EMPTY)
+    );
+    assert_eq!(
+        code_hash("0x0000000000000000000000000000000000000002"),
+        format!("0x{:x}", keccak(&code))
+    );
+}
+
 #[test]
 fn rpc_eth_transaction_count() {
     let request = r#"{
@@ -720,6 +1264,61 @@ fn rpc_eth_pending_transaction_by_hash() {
     );
 }
 
+#[test]
+fn rpc_eth_transaction_by_hash_quantity_and_input_formatting() {
+    let tester = EthTester::default();
+    let transfer = TypedTransaction::Legacy(Transaction {
+        nonce: 0x10.into(),
+        gas_price: 1_000_000_000.into(),
+        gas: 21_000.into(),
+        action: Action::Call(Address::from(5)),
+        value: 0.into(),
+        data: vec![],
+    })
+    .fake_sign(Address::from(1));
+    let mut data = vec![0xa9, 0x05, 0x9c, 0xbb];
+    data.extend(vec![0u8; 31]);
+    data.push(0x01);
+    let contract_call = TypedTransaction::Legacy(Transaction {
+        nonce: 0.into(),
+        gas_price: 0x100.into(),
+        gas: 0x10000.into(),
+        action: Action::Call(Address::from(6)),
+        value: 0x0de0b6b3a7640000u64.into(),
+        data: data.clone(),
+    })
+    .fake_sign(Address::from(1));
+    {
+        let mut pending = tester.miner.pending_transactions.lock();
+        pending.insert(transfer.hash(), transfer.clone());
+        pending.insert(contract_call.hash(), contract_call.clone());
+    }
+
+    let transaction = |hash: H256| {
+        let request = r#"{"jsonrpc": "2.0", "method": "eth_getTransactionByHash", "params": [""#
+            .to_owned()
+            + &format!("0x{:x}", hash)
+            + r#""], "id": 1}"#;
+        let response = tester.io.handle_request_sync(&request).unwrap();
+        let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+        response["result"].clone()
+    };
+
+    let result = transaction(transfer.hash());
+    assert_eq!(result["nonce"], "0x10");
+    assert_eq!(result["gas"], "0x5208");
+    assert_eq!(result["gasPrice"], "0x3b9aca00");
+    assert_eq!(result["value"], "0x0");
+    assert_eq!(result["input"], "0x");
+
+    let result = transaction(contract_call.hash());
+    assert_eq!(result["nonce"], "0x0");
+    assert_eq!(result["gas"], "0x10000");
+    assert_eq!(result["gasPrice"], "0x100");
+    assert_eq!(result["value"], "0xde0b6b3a7640000");
+    assert_eq!(result["input"], format!("0x{}", data.to_hex()));
+}
+
 #[test]
 fn rpc_eth_uncle_count_by_block_hash() {
     let request = r#"{
@@ -752,6 +1351,86 @@ fn rpc_eth_uncle_count_by_block_number() {
     );
 }
 
+#[test]
+fn rpc_eth_genesis_block_by_number() {
+    let tester = EthTester::default();
+    let genesis_hash = tester.client.block_hash(BlockId::Number(0)).unwrap();
+    let request = |number: &str| {
+        let request = format!(
+            r#"{{"jsonrpc": "2.0", "method": "eth_getBlockByNumber", "params": ["{}", false], "id": 1}}"#,
+            number
+        );
+        let response = tester.io.handle_request_sync(&request).unwrap();
+        let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+        response["result"].clone()
+    };
+
+    let genesis = request("0x0");
+    assert_eq!(genesis["number"], "0x0");
+    assert_eq!(genesis["hash"], format!("0x{:x}", genesis_hash).as_str());
+    assert_eq!(
+        genesis["parentHash"],
+        format!("0x{:x}", H256::zero()).as_str()
+    );
+    assert!(genesis["transactions"].as_array().unwrap().is_empty());
+    assert_eq!(request("earliest"), genesis);
+    assert_eq!(request("latest"), genesis);
+}
+
+#[test]
+fn rpc_eth_block_miner_is_recovered_poa_authority() {
+    let client =
+        TestBlockChainClient::new_with_spec(ethcore::ethereum::new_goerli(&::std::env::temp_dir()));
+    let tester = EthTester::new_with_client(Arc::new(client), Default::default());
+    let authority = Random.generate().unwrap();
+    tester.client.add_clique_sealed_block(&authority);
+
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "eth_getBlockByNumber",
+		"params": ["0x1", false],
+		"id": 1
+	}"#;
+    let response: ::serde_json::Value =
+        ::serde_json::from_str(&tester.io.handle_request_sync(request).unwrap()).unwrap();
+
+    assert_eq!(
+        response["result"]["miner"],
+        format!("{:?}", authority.address()).as_str()
+    );
+    assert_eq!(
+        response["result"]["author"],
+        format!("{:?}", Address::zero()).as_str()
+    );
+}
+
+#[test]
+fn rpc_eth_block_uncles() {
+    let tester = EthTester::default();
+    tester.client.add_blocks(1, EachBlockWith::Uncles(2));
+
+    let uncles = tester.client.uncle_hashes(BlockId::Number(1)).unwrap();
+    assert_eq!(uncles.len(), 2);
+    assert!(uncles[0] != uncles[1]);
+
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "eth_getBlockByNumber",
+		"params": ["0x1", false],
+		"id": 1
+	}"#;
+    let response: ::serde_json::Value =
+        ::serde_json::from_str(&tester.io.handle_request_sync(request).unwrap()).unwrap();
+    let expected = ::serde_json::Value::Array(
+        uncles
+            .iter()
+            .map(|hash| format!("0x{:x}", hash).into())
+            .collect(),
+    );
+
+    assert_eq!(response["result"]["uncles"], expected);
+}
+
 #[test]
 fn rpc_eth_code() {
     let tester = EthTester::default();
@@ -786,6 +1465,7 @@ fn rpc_eth_call_latest() {
         trace: vec![],
         vm_trace: None,
         state_diff: None,
+        accessed: Default::default(),
     }));
 
     let request = r#"{
@@ -825,6 +1505,7 @@ fn rpc_eth_call_pending() {
         trace: vec![],
         vm_trace: None,
         state_diff: None,
+        accessed: Default::default(),
     }));
 
     let request = r#"{
@@ -865,6 +1546,7 @@ fn rpc_eth_call() {
         trace: vec![],
         vm_trace: None,
         state_diff: None,
+        accessed: Default::default(),
     }));
 
     let request = r#"{
@@ -904,6 +1586,7 @@ fn rpc_eth_call_default_block() {
         trace: vec![],
         vm_trace: None,
         state_diff: None,
+        accessed: Default::default(),
     }));
 
     let request = r#"{
@@ -942,6 +1625,7 @@ fn rpc_eth_estimate_gas() {
         trace: vec![],
         vm_trace: None,
         state_diff: None,
+        accessed: Default::default(),
     }));
 
     let request = r#"{
@@ -981,6 +1665,7 @@ fn rpc_eth_estimate_gas_pending() {
         trace: vec![],
         vm_trace: None,
         state_diff: None,
+        accessed: Default::default(),
     }));
 
     let request = r#"{
@@ -1021,6 +1706,7 @@ fn rpc_eth_estimate_gas_default_block() {
         trace: vec![],
         vm_trace: None,
         state_diff: None,
+        accessed: Default::default(),
     }));
 
     let request = r#"{
@@ -1126,6 +1812,55 @@ fn rpc_eth_send_raw_transaction() {
     assert_eq!(tester.io.handle_request_sync(&req), Some(res));
 }
 
+#[test]
+fn rpc_eth_send_raw_transaction_reports_queue_pressure() {
+    let tester = EthTester::default();
+    *tester.miner.queue_limit.write() = 4;
+    *tester.miner.queue_pressure_threshold.write() = Some(50);
+    let keypair = Random.generate().unwrap();
+
+    let submit = |nonce: u64, params: &str| {
+        let t = TypedTransaction::Legacy(Transaction {
+            nonce: nonce.into(),
+            gas_price: U256::from(0x9184e72a000u64),
+            gas: U256::from(0x76c0),
+            action: Action::Call(Address::from(5)),
+            value: U256::from(0x9184e72au64),
+            data: vec![],
+        })
+        .sign(keypair.secret(), None);
+        let req = r#"{"jsonrpc": "2.0", "method": "eth_sendRawTransaction", "params": ["0x"#
+            .to_owned()
+            + &t.encode().to_hex()
+            + params
+            + r#"], "id": 1}"#;
+        (t.hash(), tester.io.handle_request_sync(&req).unwrap())
+    };
+
+    // plain submissions keep returning the hash only
+    let (hash, res) = submit(0, r#"""#);
+    assert_eq!(
+        res,
+        format!(r#"{{"jsonrpc":"2.0","result":"0x{:x}","id":1}}"#, hash)
+    );
+
+    let (hash, res) = submit(1, r#"", true"#);
+    assert_eq!(
+        res,
+        format!(
+            r#"{{"jsonrpc":"2.0","result":{{"hash":"0x{:x}","queuePressure":50}},"id":1}}"#,
+            hash
+        )
+    );
+
+    let (hash, res) = submit(2, r#"", false"#);
+    assert_eq!(
+        res,
+        format!(r#"{{"jsonrpc":"2.0","result":"0x{:x}","id":1}}"#, hash)
+    );
+    assert_eq!(tester.miner.imported_transactions.lock().len(), 3);
+}
+
 #[test]
 fn rpc_eth_transaction_receipt() {
     let receipt = LocalizedReceipt {
@@ -1192,6 +1927,59 @@ fn rpc_eth_transaction_receipt() {
     );
 }
 
+#[test]
+fn rpc_eth_transaction_receipt_revert_reason() {
+    let tester = EthTester::new_with_options(EthClientOptions::with(|options| {
+        options.receipt_revert_reason = true;
+    }));
+    let hash = H256::from(1);
+    tester.client.set_transaction_receipt(
+        TransactionId::Hash(hash),
+        LocalizedReceipt {
+            from: Address::from(2),
+            to: Some(Address::from(3)),
+            transaction_hash: hash,
+            transaction_index: 0,
+            transaction_type: TypedTxId::Legacy,
+            block_hash: H256::from(4),
+            block_number: 0,
+            cumulative_gas_used: U256::from(0x5208),
+            gas_used: U256::from(0x5208),
+            contract_address: None,
+            logs: vec![],
+            log_bloom: 0.into(),
+            outcome: TransactionOutcome::StatusCode(0),
+        },
+    );
+    let output = "08c379a0\
+		0000000000000000000000000000000000000000000000000000000000000020\
+		000000000000000000000000000000000000000000000000000000000000000e\
+		4e6f7420656e6f75676820455448000000000000000000000000000000000000"
+        .from_hex()
+        .unwrap();
+    tester.client.set_execution_result(Ok(Executed {
+        exception: Some(::vm::Error::Reverted),
+        gas: U256::zero(),
+        gas_used: U256::from(0x5208),
+        refunded: U256::zero(),
+        cumulative_gas_used: U256::zero(),
+        logs: vec![],
+        contracts_created: vec![],
+        output,
+        trace: vec![],
+        vm_trace: None,
+        state_diff: None,
+        accessed: Default::default(),
+    }));
+
+    let request = r#"{"jsonrpc": "2.0", "method": "eth_getTransactionReceipt", "params": ["0x0000000000000000000000000000000000000000000000000000000000000001"], "id": 1}"#;
+    let response = tester.io.handle_request_sync(request).unwrap();
+    let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+
+    assert_eq!(response["result"]["status"], "0x0");
+    assert_eq!(response["result"]["revertReason"], "Not enough ETH");
+}
+
 #[test]
 fn rpc_eth_transaction_receipt_null() {
     let tester = EthTester::default();
@@ -24,12 +24,17 @@ use jsonrpc_pubsub::Session;
 
 use std::time::Duration;
 
-use v1::{EthPubSub, EthPubSubClient, Metadata};
+use v1::{
+    helpers::block_import::sync_status,
+    tests::helpers::{Config, TestSnapshotService, TestSyncProvider},
+    EthPubSub, EthPubSubClient, Metadata,
+};
 
 use ethcore::client::{
     ChainNotify, ChainRoute, ChainRouteType, EachBlockWith, NewBlocks, TestBlockChainClient,
 };
 use parity_runtime::Runtime;
+use sync::SyncState;
 
 const DURATION_ZERO: Duration = Duration::from_millis(0);
 
@@ -206,6 +211,106 @@ fn should_subscribe_to_logs() {
     assert_eq!(res, None);
 }
 
+#[test]
+fn should_notify_removed_logs_on_reorg() {
+    use ethcore::client::BlockInfo;
+    use ethereum_types::H256;
+    use types::{
+        ids::BlockId,
+        log_entry::{LocalizedLogEntry, LogEntry},
+    };
+
+    // given
+    let el = Runtime::with_thread_count(1);
+    let mut client = TestBlockChainClient::new();
+    client.add_blocks(2, EachBlockWith::Transaction);
+    // `old` gets reorged out in favour of `new`
+    let old = client.block_hash_delta_minus(2);
+    let new = client.block_hash_delta_minus(1);
+    let log = |block_hash: H256, address: u64| {
+        let block = client.block(BlockId::Hash(block_hash)).unwrap();
+        LocalizedLogEntry {
+            entry: LogEntry {
+                address: address.into(),
+                topics: vec![],
+                data: vec![],
+            },
+            block_hash,
+            block_number: block.header().number(),
+            transaction_hash: block.transactions()[0].hash(),
+            transaction_index: 0,
+            log_index: 0,
+            transaction_log_index: 0,
+        }
+    };
+    let logs = vec![log(old, 5), log(new, 6)];
+    client.set_logs(logs.clone());
+
+    let pubsub = EthPubSubClient::new_test(Arc::new(client), el.executor());
+    let handler = pubsub.handler().upgrade().unwrap();
+    let pubsub = pubsub.to_delegate();
+
+    let mut io = MetaIoHandler::default();
+    io.extend_with(pubsub);
+
+    let mut metadata = Metadata::default();
+    let (sender, receiver) = futures::sync::mpsc::channel(8);
+    metadata.session = Some(Arc::new(Session::new(sender)));
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "eth_subscribe", "params": ["logs", {}], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"0x416d77337e24399d","id":1}"#;
+    assert_eq!(
+        io.handle_request_sync(request, metadata.clone()),
+        Some(response.to_owned())
+    );
+
+    let notification = |log: &LocalizedLogEntry, removed: bool| {
+        format!(
+            r#"{{"jsonrpc":"2.0","method":"eth_subscription","params":{{"result":{{"address":"{:?}","blockHash":"{:?}","blockNumber":"0x{:x}","data":"0x","logIndex":"0x0","removed":{},"topics":[],"transactionHash":"{:?}","transactionIndex":"0x0","transactionLogIndex":"0x0","type":"{}"}},"subscription":"0x416d77337e24399d"}}}}"#,
+            log.entry.address,
+            log.block_hash,
+            log.block_number,
+            removed,
+            log.transaction_hash,
+            if removed { "removed" } else { "mined" },
+        )
+    };
+
+    // when the old block is imported
+    handler.new_blocks(NewBlocks::new(
+        vec![],
+        vec![],
+        ChainRoute::new(vec![(old, ChainRouteType::Enacted)]),
+        vec![],
+        vec![],
+        DURATION_ZERO,
+        false,
+    ));
+    let (res, receiver) = receiver.into_future().wait().unwrap();
+    assert_eq!(res, Some(notification(&logs[0], false)));
+
+    // and then reorged out
+    handler.new_blocks(NewBlocks::new(
+        vec![],
+        vec![],
+        ChainRoute::new(vec![
+            (old, ChainRouteType::Retracted),
+            (new, ChainRouteType::Enacted),
+        ]),
+        vec![],
+        vec![],
+        DURATION_ZERO,
+        false,
+    ));
+
+    // then
+    let (res, receiver) = receiver.into_future().wait().unwrap();
+    assert_eq!(res, Some(notification(&logs[0], true)));
+    let (res, _receiver) = receiver.into_future().wait().unwrap();
+    assert_eq!(res, Some(notification(&logs[1], false)));
+}
+
 #[test]
 fn should_subscribe_to_pending_transactions() {
     // given
@@ -263,26 +368,51 @@ fn should_subscribe_to_pending_transactions() {
 }
 
 #[test]
-fn should_return_unimplemented() {
+fn should_subscribe_to_syncing() {
     // given
     let el = Runtime::with_thread_count(1);
-    let client = TestBlockChainClient::new();
-    let pubsub = EthPubSubClient::new_test(Arc::new(client), el.executor());
+    let client = Arc::new(TestBlockChainClient::new());
+    let snapshot = TestSnapshotService::new();
+    let sync = TestSyncProvider::new(Config {
+        network_id: 3,
+        num_peers: 120,
+    });
+    let pubsub = EthPubSubClient::new_test(client.clone(), el.executor());
+    let handler = pubsub.handler().upgrade().unwrap();
     let pubsub = pubsub.to_delegate();
 
     let mut io = MetaIoHandler::default();
     io.extend_with(pubsub);
 
     let mut metadata = Metadata::default();
-    let (sender, _receiver) = futures::sync::mpsc::channel(8);
+    let (sender, receiver) = futures::sync::mpsc::channel(8);
     metadata.session = Some(Arc::new(Session::new(sender)));
 
     // Subscribe
-    let response = r#"{"jsonrpc":"2.0","error":{"code":-32000,"message":"This request is not implemented yet. Please create an issue on Github repo."},"id":1}"#;
     let request =
         r#"{"jsonrpc": "2.0", "method": "eth_subscribe", "params": ["syncing"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"0x416d77337e24399d","id":1}"#;
     assert_eq!(
         io.handle_request_sync(request, metadata.clone()),
         Some(response.to_owned())
     );
+
+    // Check notifications
+    {
+        let mut status = sync.status.write();
+        status.state = SyncState::Blocks;
+        status.highest_block_number = Some(10);
+    }
+    handler.notify_syncing(sync_status(&*client, &snapshot, &sync));
+    // unchanged status is not sent again
+    handler.notify_syncing(sync_status(&*client, &snapshot, &sync));
+    let (res, receiver) = receiver.into_future().wait().unwrap();
+    let response = r#"{"jsonrpc":"2.0","method":"eth_subscription","params":{"result":{"currentBlock":"0x0","highestBlock":"0xa","startingBlock":"0x0","warpChunksAmount":null,"warpChunksProcessed":null},"subscription":"0x416d77337e24399d"}}"#;
+    assert_eq!(res, Some(response.into()));
+
+    sync.status.write().state = SyncState::Idle;
+    handler.notify_syncing(sync_status(&*client, &snapshot, &sync));
+    let (res, _receiver) = receiver.into_future().wait().unwrap();
+    let response = r#"{"jsonrpc":"2.0","method":"eth_subscription","params":{"result":false,"subscription":"0x416d77337e24399d"}}"#;
+    assert_eq!(res, Some(response.into()));
 }
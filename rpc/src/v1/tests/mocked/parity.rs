@@ -29,7 +29,7 @@ use types::{
 use super::manage_network::TestManageNetwork;
 use jsonrpc_core::IoHandler;
 use v1::{
-    helpers::{external_signer::SignerService, NetworkSettings},
+    helpers::{external_signer::SignerService, NetworkSettings, NodeName},
     metadata::Metadata,
     tests::helpers::{Config, TestMinerService, TestSyncProvider},
     Parity, ParityClient,
@@ -44,6 +44,7 @@ pub struct Dependencies {
     pub sync: Arc<TestSyncProvider>,
     pub logger: Arc<RotatingLogger>,
     pub settings: Arc<NetworkSettings>,
+    pub node_name: Arc<NodeName>,
     pub network: Arc<dyn ManageNetwork>,
     pub ws_address: Option<Host>,
 }
@@ -66,7 +67,10 @@ impl Dependencies {
                 rpc_enabled: true,
                 rpc_interface: "all".to_owned(),
                 rpc_port: 8545,
+                ws_enabled: true,
+                ws_port: 18546,
             }),
+            node_name: Arc::new(NodeName::new("mynode".to_owned())),
             network: Arc::new(TestManageNetwork),
             ws_address: Some("127.0.0.1:18546".into()),
         }
@@ -80,6 +84,7 @@ impl Dependencies {
             self.network.clone(),
             self.logger.clone(),
             self.settings.clone(),
+            self.node_name.clone(),
             signer,
             self.ws_address.clone(),
             None,
@@ -139,6 +144,57 @@ fn rpc_parity_gas_floor_target() {
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_gas_ceil_target() {
+    let deps = Dependencies::new();
+    let io = deps.default_client();
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_gasCeilTarget", "params": [], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"0xd431","id":1}"#;
+
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_authoring_getters_follow_setters() {
+    use fake_fetch::FakeFetch;
+    use v1::{helpers::dapps::RecentDapps, ParitySet, ParitySetClient};
+
+    let deps = Dependencies::new();
+    let mut io = deps.default_client();
+    io.extend_with(
+        ParitySetClient::new(
+            &deps.client,
+            &deps.miner,
+            &deps.network,
+            None,
+            &Arc::new(RecentDapps::default()),
+            None,
+            &deps.node_name,
+            FakeFetch::new(Some(1)),
+        )
+        .to_delegate(),
+    );
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_setGasCeilTarget", "params":["0x989680"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":true,"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_setExtraData", "params":["0xcafe"], "id": 1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_gasCeilTarget", "params": [], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"0x989680","id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_extraData", "params": [], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"0xcafe","id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    // the floor target is left untouched
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_gasFloorTarget", "params": [], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"0x3039","id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
 #[test]
 fn rpc_parity_min_gas_price() {
     let deps = Dependencies::new();
@@ -191,11 +247,15 @@ fn rpc_parity_transactions_limit() {
 fn rpc_parity_net_chain() {
     let deps = Dependencies::new();
     let io = deps.default_client();
+    *deps.client.spec_name.write() = "goerli".into();
 
     let request = r#"{"jsonrpc": "2.0", "method": "parity_netChain", "params":[], "id": 1}"#;
-    let response = r#"{"jsonrpc":"2.0","result":"testchain","id":1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"goerli","id":1}"#;
 
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_chain", "params":[], "id": 1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
 #[test]
@@ -231,6 +291,47 @@ fn rpc_parity_net_port() {
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_ports() {
+    let mut deps = Dependencies::new();
+    let io1 = deps.default_client();
+    deps.settings = Arc::new(NetworkSettings {
+        rpc_enabled: false,
+        ws_enabled: false,
+        ..Default::default()
+    });
+    deps.ws_address = None;
+    let io2 = deps.default_client();
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_listeningPort", "params":[], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":30303,"id":1}"#;
+    assert_eq!(io1.handle_request_sync(request), Some(response.to_owned()));
+    assert_eq!(io2.handle_request_sync(request), Some(response.to_owned()));
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_rpcPort", "params":[], "id": 1}"#;
+    let response1 = r#"{"jsonrpc":"2.0","result":8545,"id":1}"#;
+    let response2 = r#"{"jsonrpc":"2.0","result":null,"id":1}"#;
+    assert_eq!(io1.handle_request_sync(request), Some(response1.to_owned()));
+    assert_eq!(io2.handle_request_sync(request), Some(response2.to_owned()));
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_wsPort", "params":[], "id": 1}"#;
+    let response1 = r#"{"jsonrpc":"2.0","result":18546,"id":1}"#;
+    let response2 = r#"{"jsonrpc":"2.0","result":null,"id":1}"#;
+    assert_eq!(io1.handle_request_sync(request), Some(response1.to_owned()));
+    assert_eq!(io2.handle_request_sync(request), Some(response2.to_owned()));
+}
+
+#[test]
+fn rpc_parity_ws_port_with_hosts() {
+    let mut deps = Dependencies::new();
+    deps.ws_address = Some("example.com".into());
+    let io = deps.default_client();
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_wsPort", "params":[], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":18546,"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
 #[test]
 fn rpc_parity_rpc_settings() {
     let deps = Dependencies::new();
@@ -290,6 +391,97 @@ fn rpc_parity_pending_transactions() {
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_pending_transactions_by_address() {
+    use types::transaction::{Transaction, TypedTransaction};
+    let deps = Dependencies::new();
+    let io = deps.default_client();
+    let (alice, bob, carol) = (Address::from(1), Address::from(2), Address::from(3));
+    let queue = |sender: Address, nonce: u64| {
+        let tx = TypedTransaction::Legacy(Transaction {
+            value: 5.into(),
+            gas: 21_000.into(),
+            gas_price: 2.into(),
+            action: ::types::transaction::Action::Create,
+            data: vec![],
+            nonce: nonce.into(),
+        })
+        .fake_sign(sender);
+        let hash = tx.hash();
+        deps.miner.queue_transaction(tx);
+        hash
+    };
+    let first = queue(alice, 0);
+    queue(bob, 0);
+    let second = queue(alice, 1);
+    queue(carol, 0);
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_pendingTransactionsByAddress", "params":[""#
+            .to_owned()
+            + &format!("{:?}", alice)
+            + r#""], "id": 1}"#;
+    let response = io.handle_request_sync(&request).unwrap();
+    let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+    let result = response["result"].as_array().unwrap();
+
+    let hashes = result
+        .iter()
+        .map(|tx| tx["hash"].as_str().unwrap().to_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        hashes,
+        vec![format!("{:?}", first), format!("{:?}", second)]
+    );
+    assert!(result
+        .iter()
+        .all(|tx| tx["from"] == format!("{:?}", alice).as_str()));
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_pendingTransactionsByAddress", "params":["0x0000000000000000000000000000000000000004"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":[],"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_harvest_pending_transactions() {
+    use rustc_hex::FromHex;
+    use types::transaction::{Transaction, TypedTransaction, UnverifiedTransaction};
+    let deps = Dependencies::new();
+    let io = deps.default_client();
+    let mut expected = (0..3u64)
+        .map(|nonce| {
+            let tx = TypedTransaction::Legacy(Transaction {
+                value: 5.into(),
+                gas: 21_000.into(),
+                gas_price: 2.into(),
+                action: ::types::transaction::Action::Create,
+                data: vec![],
+                nonce: nonce.into(),
+            })
+            .fake_sign(Address::from(1));
+            deps.miner.queue_transaction(tx.clone());
+            UnverifiedTransaction::from(tx)
+        })
+        .collect::<Vec<_>>();
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_harvestPendingTransactions", "params":[], "id": 1}"#;
+    let response = io.handle_request_sync(request).unwrap();
+    let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+    let mut harvested = response["result"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|raw| {
+            let raw: Vec<u8> = raw.as_str().unwrap()[2..].from_hex().unwrap();
+            TypedTransaction::decode(&raw).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    expected.sort_by_key(|tx| tx.hash());
+    harvested.sort_by_key(|tx| tx.hash());
+    assert_eq!(harvested, expected);
+}
+
 #[test]
 fn rpc_parity_encrypt() {
     let deps = Dependencies::new();
@@ -400,6 +592,50 @@ fn rpc_parity_local_transactions() {
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_local_transactions_requeued_after_reorg() {
+    use ethcore::{
+        client::{BlockChainClient, BlockInfo, EachBlockWith},
+        miner::MinerService,
+    };
+    use types::{ids::BlockId, transaction::SignedTransaction};
+
+    let deps = Dependencies::new();
+    let io = deps.default_client();
+
+    // mine a local transaction
+    deps.client.add_blocks(1, EachBlockWith::Transaction);
+    let block_hash = deps.client.block_hash(BlockId::Number(1)).unwrap();
+    let tx = deps
+        .client
+        .block(BlockId::Hash(block_hash))
+        .unwrap()
+        .transactions()[0]
+        .clone();
+    let tx = SignedTransaction::new(tx).unwrap();
+    let hash = tx.hash();
+    deps.miner.local_transactions.lock().insert(
+        hash,
+        LocalTransactionStatus::Mined(Arc::new(
+            ::miner::pool::VerifiedTransaction::from_pending_block_transaction(tx),
+        )),
+    );
+
+    // reorg it out
+    deps.miner
+        .chain_new_blocks(&*deps.client, &[], &[], &[], &[block_hash], false);
+
+    assert!(deps.miner.pending_transactions.lock().contains_key(&hash));
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_localTransactions", "params":[], "id": 1}"#;
+    let response = format!(
+        r#"{{"jsonrpc":"2.0","result":{{"0x{:x}":{{"status":"pending"}}}},"id":1}}"#,
+        hash
+    );
+
+    assert_eq!(io.handle_request_sync(request), Some(response));
+}
+
 #[test]
 fn rpc_parity_chain_status() {
     let deps = Dependencies::new();
@@ -414,6 +650,201 @@ fn rpc_parity_chain_status() {
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_storage_ats() {
+    let deps = Dependencies::new();
+    let io = deps.default_client();
+
+    let address = Address::from(1);
+    deps.client.set_storage(
+        address,
+        H256::from(U256::from(0)),
+        H256::from(U256::from(0x2a)),
+    );
+    deps.client.set_storage(
+        address,
+        H256::from(U256::from(7)),
+        H256::from(U256::from(0xdeadbeefu64)),
+    );
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_getStorageAts", "params":["0x0000000000000000000000000000000000000001", ["0x0", "0x7", "0x8"], "latest"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":["0x000000000000000000000000000000000000000000000000000000000000002a","0x00000000000000000000000000000000000000000000000000000000deadbeef","0x0000000000000000000000000000000000000000000000000000000000000000"],"id":1}"#;
+
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_storage_at_strict() {
+    let deps = Dependencies::new();
+    let io = deps.default_client();
+
+    let contract = Address::from(1);
+    deps.client.set_code(contract, vec![0x60, 0x00]);
+    deps.client.set_storage(
+        contract,
+        H256::from(U256::from(4)),
+        H256::from(U256::from(7)),
+    );
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_getStorageAtStrict", "params":["0x0000000000000000000000000000000000000001", "0x4", "latest"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"0x0000000000000000000000000000000000000000000000000000000000000007","id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_getStorageAtStrict", "params":["0x0000000000000000000000000000000000000002", "0x4", "latest"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"Account 0x0000000000000000000000000000000000000002 has no code, so it cannot have any storage."},"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_consensus_capability() {
+    use ethcore::{client::EachBlockWith, spec::Spec};
+    use std::env;
+
+    const UNSUPPORTED_FORK_SPEC: &'static [u8] = br#"{
+		"name": "Morden",
+		"engine": {
+			"null": {
+				"params": {}
+			}
+		},
+		"params": {
+			"gasLimitBoundDivisor": "0x0400",
+			"accountStartNonce": "0x0",
+			"maximumExtraDataSize": "0x20",
+			"minGasLimit": "0x1388",
+			"networkID" : "0x2",
+			"scheduledForks": {
+				"berlin": "0x0",
+				"unknownFutureFork": "0x2"
+			}
+		},
+		"genesis": {
+			"seal": {
+				"ethereum": {
+					"nonce": "0x00006d6f7264656e",
+					"mixHash": "0x00000000000000000000000000000000000000647572616c65787365646c6578"
+				}
+			},
+			"difficulty": "0x20000",
+			"author": "0x0000000000000000000000000000000000000000",
+			"timestamp": "0x00",
+			"parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+			"extraData": "0x",
+			"gasLimit": "0x2fefd8"
+		},
+		"accounts": {}
+	}"#;
+
+    let mut deps = Dependencies::new();
+    deps.client = Arc::new(TestBlockChainClient::new_with_spec(
+        Spec::load(&env::temp_dir(), UNSUPPORTED_FORK_SPEC).expect("invalid chain spec"),
+    ));
+    let io = deps.default_client();
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_consensusCapability", "params":[], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":{"capableUntil":2},"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+
+    deps.client.add_blocks(2, EachBlockWith::Nothing);
+
+    let response = r#"{"jsonrpc":"2.0","result":{"incapableSince":2},"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_registry_address() {
+    use ethcore::spec::Spec;
+    use std::env;
+
+    const REGISTRAR_SPEC: &'static [u8] = br#"{
+		"name": "Morden",
+		"engine": {
+			"null": {
+				"params": {}
+			}
+		},
+		"params": {
+			"gasLimitBoundDivisor": "0x0400",
+			"accountStartNonce": "0x0",
+			"maximumExtraDataSize": "0x20",
+			"minGasLimit": "0x1388",
+			"networkID" : "0x2",
+			"registrar": "0x0000000000000000000000000000000000001337"
+		},
+		"genesis": {
+			"seal": {
+				"ethereum": {
+					"nonce": "0x00006d6f7264656e",
+					"mixHash": "0x00000000000000000000000000000000000000647572616c65787365646c6578"
+				}
+			},
+			"difficulty": "0x20000",
+			"author": "0x0000000000000000000000000000000000000000",
+			"timestamp": "0x00",
+			"parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+			"extraData": "0x",
+			"gasLimit": "0x2fefd8"
+		},
+		"accounts": {}
+	}"#;
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_registryAddress", "params":[], "id": 1}"#;
+
+    let deps = Dependencies::new();
+    let io = deps.default_client();
+    let response = r#"{"jsonrpc":"2.0","result":null,"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+
+    let mut deps = Dependencies::new();
+    deps.client = Arc::new(TestBlockChainClient::new_with_spec(
+        Spec::load(&env::temp_dir(), REGISTRAR_SPEC).expect("invalid chain spec"),
+    ));
+    let io = deps.default_client();
+    let response =
+        r#"{"jsonrpc":"2.0","result":"0x0000000000000000000000000000000000001337","id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_new_account_from_generated_phrase() {
+    use accounts::AccountProvider;
+    use v1::{ParityAccounts, ParityAccountsClient};
+
+    let deps = Dependencies::new();
+    let accounts = Arc::new(AccountProvider::transient_provider());
+    let mut io = deps.default_client();
+    io.extend_with(ParityAccounts::to_delegate(ParityAccountsClient::new(
+        &accounts,
+    )));
+
+    let result = |request: &str| {
+        let response = io.handle_request_sync(request).unwrap();
+        let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+        response["result"].as_str().unwrap().to_owned()
+    };
+
+    let phrase = result(
+        r#"{"jsonrpc": "2.0", "method": "parity_generateSecretPhrase", "params": [], "id": 1}"#,
+    );
+    assert_eq!(phrase.split_whitespace().count(), 12);
+
+    let address = result(&format!(
+        r#"{{"jsonrpc": "2.0", "method": "parity_phraseToAddress", "params": ["{}"], "id": 1}}"#,
+        phrase
+    ));
+    let created = result(&format!(
+        r#"{{"jsonrpc": "2.0", "method": "parity_newAccountFromPhrase", "params": ["{}", "password"], "id": 1}}"#,
+        phrase
+    ));
+
+    assert_eq!(created, address);
+    assert_eq!(
+        accounts.accounts().unwrap(),
+        vec![address[2..].parse::<Address>().unwrap()]
+    );
+}
+
 #[test]
 fn rpc_parity_node_kind() {
     let deps = Dependencies::new();
@@ -426,6 +857,22 @@ fn rpc_parity_node_kind() {
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_node_kind_public() {
+    let mut deps = Dependencies::new();
+    deps.settings = Arc::new(NetworkSettings {
+        rpc_interface: "0.0.0.0".to_owned(),
+        ..Default::default()
+    });
+    let io = deps.default_client();
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_nodeKind", "params":[], "id": 1}"#;
+    let response =
+        r#"{"jsonrpc":"2.0","result":{"availability":"public","capability":"full"},"id":1}"#;
+
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
 #[test]
 fn rpc_parity_call() {
     let deps = Dependencies::new();
@@ -441,6 +888,7 @@ fn rpc_parity_call() {
         trace: vec![],
         vm_trace: None,
         state_diff: None,
+        accessed: Default::default(),
     }));
     let io = deps.default_client();
 
@@ -463,6 +911,138 @@ fn rpc_parity_call() {
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_call_detailed() {
+    let deps = Dependencies::new();
+    deps.client.set_execution_result(Ok(Executed {
+        exception: None,
+        gas: U256::zero(),
+        gas_used: U256::from(0x5208),
+        refunded: U256::from(0x2710),
+        cumulative_gas_used: U256::zero(),
+        logs: vec![],
+        contracts_created: vec![],
+        output: vec![0x12, 0x34, 0xff],
+        trace: vec![],
+        vm_trace: None,
+        state_diff: None,
+        accessed: Default::default(),
+    }));
+    let io = deps.default_client();
+
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_callDetailed",
+		"params": [{
+			"from": "0xb60e8dd61c5d32be8058bb8eb970870f07233155",
+			"to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+			"gas": "0x76c0",
+			"data": "0xd46e8dd6"
+		},
+		"latest"],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","result":{"gasRefunded":"0x2710","gasUsed":"0x5208","output":"0x1234ff","success":true},"id":1}"#;
+
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_call_detailed_reverted() {
+    let deps = Dependencies::new();
+    deps.client.set_execution_result(Ok(Executed {
+        exception: Some(vm::Error::Reverted),
+        gas: U256::zero(),
+        gas_used: U256::from(0x5208),
+        refunded: U256::zero(),
+        cumulative_gas_used: U256::zero(),
+        logs: vec![],
+        contracts_created: vec![],
+        output: vec![0x08, 0xc3, 0x79, 0xa0],
+        trace: vec![],
+        vm_trace: None,
+        state_diff: None,
+        accessed: Default::default(),
+    }));
+    let io = deps.default_client();
+
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_callDetailed",
+		"params": [{
+			"to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+			"data": "0xd46e8dd6"
+		}],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","result":{"error":"Reverted","gasRefunded":"0x0","gasUsed":"0x5208","output":"0x08c379a0","success":false},"id":1}"#;
+
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_call_with_access_report() {
+    use std::{collections::BTreeMap, str::FromStr};
+    use types::{
+        account_diff::{AccountDiff, Diff},
+        state_diff::StateDiff,
+    };
+
+    let sender = Address::from_str("b60e8dd61c5d32be8058bb8eb970870f07233155").unwrap();
+    let contract = Address::from_str("d46e8dd67c5d32be8058bb8eb970870f07244567").unwrap();
+    let account_diff = |nonce: Diff<U256>, storage: BTreeMap<H256, Diff<H256>>| AccountDiff {
+        balance: Diff::Same,
+        nonce,
+        code: Diff::Same,
+        storage,
+    };
+    let mut written_storage = BTreeMap::new();
+    written_storage.insert(H256::from(1), Diff::Changed(H256::from(0), H256::from(1)));
+    let mut state_diff = BTreeMap::new();
+    state_diff.insert(
+        sender,
+        account_diff(Diff::Changed(0.into(), 1.into()), BTreeMap::new()),
+    );
+    state_diff.insert(contract, account_diff(Diff::Same, written_storage));
+    let mut accessed = BTreeMap::new();
+    accessed.insert(sender, Default::default());
+    accessed.insert(
+        contract,
+        vec![H256::from(0), H256::from(1)].into_iter().collect(),
+    );
+
+    let deps = Dependencies::new();
+    deps.client.set_execution_result(Ok(Executed {
+        exception: None,
+        gas: U256::zero(),
+        gas_used: U256::from(0x5208),
+        refunded: U256::zero(),
+        cumulative_gas_used: U256::zero(),
+        logs: vec![],
+        contracts_created: vec![],
+        output: vec![],
+        trace: vec![],
+        vm_trace: None,
+        state_diff: Some(StateDiff { raw: state_diff }),
+        accessed,
+    }));
+    let io = deps.default_client();
+
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_callWithAccessReport",
+		"params": [{
+			"from": "0xb60e8dd61c5d32be8058bb8eb970870f07233155",
+			"to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567"
+		},
+		"latest"],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","result":{"output":"0x","read":{"0xb60e8dd61c5d32be8058bb8eb970870f07233155":[],"0xd46e8dd67c5d32be8058bb8eb970870f07244567":["0x0000000000000000000000000000000000000000000000000000000000000000"]},"written":{"0xb60e8dd61c5d32be8058bb8eb970870f07233155":[],"0xd46e8dd67c5d32be8058bb8eb970870f07244567":["0x0000000000000000000000000000000000000000000000000000000000000001"]}},"id":1}"#;
+
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
 #[test]
 fn rpc_parity_block_receipts() {
     let deps = Dependencies::new();
@@ -497,6 +1077,125 @@ fn rpc_parity_block_receipts() {
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_get_block_reward() {
+    use ethcore::{
+        client::{BlockInfo, EachBlockWith},
+        spec::Spec,
+    };
+    use types::ids::BlockId;
+
+    let mut deps = Dependencies::new();
+    deps.client = Arc::new(TestBlockChainClient::new_with_spec(
+        Spec::new_test_with_reward(),
+    ));
+    deps.client
+        .add_block(EachBlockWith::UncleAndTransaction, |header| header);
+    let block = deps.client.block(BlockId::Number(1)).unwrap();
+    let transaction_hash = block.transaction_hashes()[0];
+    deps.client.set_transaction_receipt(
+        TransactionId::Hash(transaction_hash),
+        LocalizedReceipt {
+            transaction_hash,
+            transaction_type: TypedTxId::Legacy,
+            transaction_index: 0,
+            block_hash: block.hash(),
+            block_number: 1,
+            cumulative_gas_used: 21_000.into(),
+            gas_used: 21_000.into(),
+            contract_address: None,
+            logs: vec![],
+            log_bloom: 0.into(),
+            outcome: TransactionOutcome::Unknown,
+            to: None,
+            from: 9.into(),
+        },
+    );
+    let io = deps.default_client();
+
+    // base reward of 5 ether, 1/32 of it for including the uncle, 21000 gas at 200 gwei
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_getBlockReward", "params": ["0x1"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":{"blockReward":"0x4563918244f40000","total":"0x479d99ef0b2a2000","transactionFees":"0xeebe0b40e8000","uncleInclusionReward":"0x22b1c8c1227a000","uncleRewards":["0x4563918244f40000"]},"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_getBlockReward", "params": ["0x2"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":null,"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_transaction_receipt_with_internal_transactions() {
+    use ethcore::trace::{
+        trace::{Action, Call, CallResult, Res},
+        LocalizedTrace,
+    };
+    use vm::CallType;
+
+    let deps = Dependencies::new();
+    deps.client.set_transaction_receipt(
+        TransactionId::Hash(1.into()),
+        LocalizedReceipt {
+            transaction_hash: 1.into(),
+            transaction_type: TypedTxId::Legacy,
+            transaction_index: 0,
+            block_hash: 3.into(),
+            block_number: 0,
+            cumulative_gas_used: 21_000.into(),
+            gas_used: 21_000.into(),
+            contract_address: None,
+            logs: vec![],
+            log_bloom: 1.into(),
+            outcome: TransactionOutcome::Unknown,
+            to: Some(10.into()),
+            from: 9.into(),
+        },
+    );
+    let trace =
+        |trace_address: Vec<usize>, from: u64, to: u64, value: u64, call_type| LocalizedTrace {
+            action: Action::Call(Call {
+                from: from.into(),
+                to: to.into(),
+                value: value.into(),
+                gas: 0x100.into(),
+                input: vec![],
+                call_type,
+            }),
+            result: Res::Call(CallResult {
+                gas_used: 0x10.into(),
+                output: vec![],
+            }),
+            subtraces: 0,
+            trace_address,
+            transaction_number: Some(0),
+            transaction_hash: Some(1.into()),
+            block_number: 0,
+            block_hash: 3.into(),
+        };
+    *deps.client.traces.write() = Some(vec![
+        trace(vec![], 9, 10, 5, CallType::Call),
+        trace(vec![0], 10, 11, 3, CallType::Call),
+        trace(vec![1], 10, 12, 0, CallType::Call),
+        trace(vec![2], 10, 13, 2, CallType::DelegateCall),
+        trace(vec![3, 0], 11, 14, 1, CallType::Call),
+    ]);
+    let io = deps.default_client();
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_transactionReceipt", "params": ["0x0000000000000000000000000000000000000000000000000000000000000001"], "id": 1}"#;
+    let response = io.handle_request_sync(request).unwrap();
+    let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+
+    assert_eq!(
+        response["result"]["internalTransactions"].to_string(),
+        r#"[{"from":"0x000000000000000000000000000000000000000a","to":"0x000000000000000000000000000000000000000b","value":"0x3"},{"from":"0x000000000000000000000000000000000000000b","to":"0x000000000000000000000000000000000000000e","value":"0x1"}]"#
+    );
+    assert_eq!(
+        response["result"]["transactionHash"],
+        "0x0000000000000000000000000000000000000000000000000000000000000001"
+    );
+}
+
 #[test]
 fn rpc_status_ok() {
     let deps = Dependencies::new();
@@ -569,3 +1268,55 @@ fn rpc_parity_verify_signature() {
 
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
+
+#[test]
+fn rpc_parity_send_raw_transaction_detailed_reports_queue_pressure() {
+    use rustc_hex::ToHex;
+    use types::transaction::{Action, Transaction, TypedTransaction};
+
+    let deps = Dependencies::new();
+    *deps.miner.queue_limit.write() = 4;
+    *deps.miner.queue_pressure_threshold.write() = Some(50);
+    let io = deps.default_client();
+    let keypair = Random.generate().unwrap();
+
+    let signed = |nonce: u64| {
+        TypedTransaction::Legacy(Transaction {
+            nonce: nonce.into(),
+            gas_price: 0x9184e72a000u64.into(),
+            gas: 0x76c0.into(),
+            action: Action::Call(5.into()),
+            value: 0x9184e72au64.into(),
+            data: vec![],
+        })
+        .sign(keypair.secret(), None)
+    };
+    let submit = |nonce: u64| {
+        let tx = signed(nonce);
+        let request =
+            r#"{"jsonrpc": "2.0", "method": "parity_sendRawTransactionDetailed", "params":["0x"#
+                .to_owned()
+                + &tx.encode().to_hex()
+                + r#""], "id": 1}"#;
+        (tx.hash(), io.handle_request_sync(&request).unwrap())
+    };
+
+    let (hash, response) = submit(0);
+    assert_eq!(
+        response,
+        format!(
+            r#"{{"jsonrpc":"2.0","result":{{"hash":"0x{:x}"}},"id":1}}"#,
+            hash
+        )
+    );
+
+    let (hash, response) = submit(1);
+    assert_eq!(
+        response,
+        format!(
+            r#"{{"jsonrpc":"2.0","result":{{"hash":"0x{:x}","queuePressure":50}},"id":1}}"#,
+            hash
+        )
+    );
+    assert_eq!(deps.miner.imported_transactions.lock().len(), 2);
+}
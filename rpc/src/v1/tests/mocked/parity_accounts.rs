@@ -94,6 +94,52 @@ fn rpc_parity_accounts_info() {
     assert_eq!(io.handle_request_sync(request), Some(response));
 }
 
+#[test]
+fn rpc_parity_accounts_info_in_vault() {
+    let tempdir = TempDir::new("").unwrap();
+    let tester = setup_with_vaults_support(tempdir.path().to_str().unwrap());
+
+    let root = tester.accounts.new_account(&"".into()).unwrap();
+    let in_vault = tester.accounts.new_account(&"".into()).unwrap();
+    tester
+        .accounts
+        .set_account_name(root, "Root".into())
+        .unwrap();
+    tester
+        .accounts
+        .set_account_name(in_vault, "Vault".into())
+        .unwrap();
+    tester
+        .accounts
+        .create_vault("vault1", &"password1".into())
+        .unwrap();
+    tester.accounts.change_vault(in_vault, "vault1").unwrap();
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_accountsInfo", "params": ["vault1"], "id": 1}"#;
+    let response = format!(
+        r#"{{"jsonrpc":"2.0","result":{{"0x{:x}":{{"name":"Vault"}}}},"id":1}}"#,
+        in_vault
+    );
+    assert_eq!(tester.io.handle_request_sync(request), Some(response));
+
+    // without a vault all accounts are returned
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_accountsInfo", "params": [], "id": 1}"#;
+    let res = tester.io.handle_request_sync(request).unwrap();
+    assert!(res.contains(&format!("0x{:x}", root)));
+    assert!(res.contains(&format!("0x{:x}", in_vault)));
+
+    // closed vaults can't be queried
+    tester.accounts.close_vault("vault1").unwrap();
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_accountsInfo", "params": ["vault1"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32023,"message":"Could not fetch vault accounts.","data":"VaultNotFound"},"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+}
+
 #[test]
 fn rpc_parity_default_account() {
     let tester = setup();
@@ -122,6 +168,40 @@ fn rpc_parity_default_account() {
     assert_eq!(io.handle_request_sync(request), Some(response));
 }
 
+#[test]
+fn rpc_parity_set_default_account() {
+    let tester = setup();
+    tester.accounts.new_account(&"".into()).unwrap();
+    tester.accounts.new_account(&"".into()).unwrap();
+    let address = tester.accounts.accounts().unwrap()[1];
+
+    let request = format!(
+        r#"{{"jsonrpc": "2.0", "method": "parity_setDefaultAccount", "params": ["0x{:x}"], "id": 1}}"#,
+        address
+    );
+    let response = r#"{"jsonrpc":"2.0","result":true,"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(&request),
+        Some(response.to_owned())
+    );
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_defaultAccount", "params": [], "id": 1}"#;
+    let response = format!(
+        "{{\"jsonrpc\":\"2.0\",\"result\":\"0x{:x}\",\"id\":1}}",
+        address
+    );
+    assert_eq!(tester.io.handle_request_sync(request), Some(response));
+    assert_eq!(tester.accounts.accounts().unwrap()[0], address);
+
+    // unknown accounts can't be the default
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_setDefaultAccount", "params": ["0x0000000000000000000000000000000000000001"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32023,"message":"Could not set default account.","data":"InvalidAccount"},"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+}
+
 #[test]
 fn should_be_able_to_get_account_info() {
     let tester = setup();
@@ -156,6 +236,35 @@ fn should_be_able_to_get_account_info() {
     assert_eq!(res, Some(response));
 }
 
+#[test]
+fn should_be_able_to_get_all_accounts_info_with_kinds() {
+    let tester = setup();
+    tester.accounts.new_account(&"".into()).unwrap();
+    let address = tester.accounts.accounts().unwrap()[0];
+    let uuid = tester.accounts.accounts_info().unwrap()[&address]
+        .uuid
+        .as_ref()
+        .unwrap()
+        .clone();
+    tester
+        .accounts
+        .set_account_name(address.clone(), "Keystore".to_owned())
+        .unwrap();
+    tester
+        .accounts
+        .set_address_name(1.into(), "Watched".to_owned());
+    // address book entry for a keystore account doesn't change its kind
+    tester
+        .accounts
+        .set_address_name(address.clone(), "Book".to_owned());
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_getAllAccountsInfo", "params": [], "id": 1}"#;
+    let res = tester.io.handle_request_sync(request);
+    let response = format!("{{\"jsonrpc\":\"2.0\",\"result\":{{\"0x0000000000000000000000000000000000000001\":{{\"kind\":\"watchOnly\",\"meta\":\"{{}}\",\"name\":\"Watched\"}},\"0x{:x}\":{{\"kind\":\"keystore\",\"meta\":\"{{}}\",\"name\":\"Keystore\",\"uuid\":\"{}\"}}}},\"id\":1}}", address, uuid);
+    assert_eq!(res, Some(response));
+}
+
 #[test]
 fn should_be_able_to_set_name() {
     let tester = setup();
@@ -251,6 +360,22 @@ fn should_be_able_to_kill_account() {
     assert_eq!(accounts.len(), 0);
 }
 
+#[test]
+fn should_not_kill_account_with_wrong_password() {
+    let tester = setup();
+    let address = tester.accounts.new_account(&"password".into()).unwrap();
+
+    let request = format!(
+        r#"{{"jsonrpc": "2.0", "method": "parity_killAccount", "params": ["0x{:x}", "wrong"], "id": 1}}"#,
+        address
+    );
+    let response = r#"{"jsonrpc":"2.0","result":false,"id":1}"#;
+    let res = tester.io.handle_request_sync(&request);
+    assert_eq!(res, Some(response.into()));
+
+    assert_eq!(tester.accounts.accounts().unwrap(), vec![address]);
+}
+
 #[test]
 fn should_be_able_to_remove_address() {
     let tester = setup();
@@ -360,6 +485,55 @@ fn rpc_parity_change_vault_password() {
     );
 }
 
+#[test]
+fn rpc_parity_set_vault_password() {
+    let tempdir = TempDir::new("").unwrap();
+    let tester = setup_with_vaults_support(tempdir.path().to_str().unwrap());
+
+    let (address, _) = tester
+        .accounts
+        .new_account_and_public(&"password1".into())
+        .unwrap();
+    assert!(tester
+        .accounts
+        .create_vault("vault1", &"password1".into())
+        .is_ok());
+    assert!(tester.accounts.change_vault(address, "vault1").is_ok());
+    assert!(tester.accounts.close_vault("vault1").is_ok());
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_setVaultPassword", "params":["vault1", "password3", "password2"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":false,"id":1}"#;
+
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_setVaultPassword", "params":["vault1", "password1", "password2"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":true,"id":1}"#;
+
+    assert_eq!(
+        tester.io.handle_request_sync(request),
+        Some(response.to_owned())
+    );
+    assert!(tester
+        .accounts
+        .open_vault("vault1", &"password1".into())
+        .is_err());
+    assert!(tester
+        .accounts
+        .open_vault("vault1", &"password2".into())
+        .is_ok());
+    assert!(tester
+        .accounts
+        .sign(address, Some("password1".into()), Default::default())
+        .is_err());
+    assert!(tester
+        .accounts
+        .sign(address, Some("password2".into()), Default::default())
+        .is_ok());
+}
+
 #[test]
 fn rpc_parity_change_vault() {
     let tempdir = TempDir::new("").unwrap();
@@ -629,6 +803,86 @@ fn should_export_account() {
     assert_eq!(result, Some(response.into()));
 }
 
+#[test]
+fn should_export_created_account_as_v3_keystore() {
+    // given
+    let tester = setup();
+    let address = tester.accounts.new_account(&"export".into()).unwrap();
+    let request = r#"{"jsonrpc":"2.0","method":"parity_exportAccount","params":[""#.to_owned()
+        + &format!("0x{:x}", address)
+        + r#"", "export"],"id":1}"#;
+
+    // when
+    let response = tester.io.handle_request_sync(&request).unwrap();
+    let response: ::serde_json::Value = ::serde_json::from_str(&response).unwrap();
+    let keystore = &response["result"];
+
+    // then
+    assert_eq!(keystore["version"], 3);
+    assert_eq!(keystore["address"], format!("{:x}", address).as_str());
+    assert!(keystore["crypto"]["ciphertext"].is_string());
+    assert!(keystore["crypto"]["mac"].is_string());
+
+    // and the keystore unlocks the same account elsewhere
+    let other = setup();
+    let imported = other
+        .accounts
+        .import_wallet(keystore.to_string().as_bytes(), &"export".into(), false)
+        .unwrap();
+    assert_eq!(imported, address);
+
+    // wrong password
+    let request = request.replace("\"export\"", "\"wrong\"");
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32023,"message":"Could not export account.","data":"InvalidPassword"},"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(&request),
+        Some(response.to_owned())
+    );
+}
+
+#[test]
+fn should_import_account() {
+    // given
+    let tester = setup();
+    let keystore = r#"{"id":"6a186c80-7797-cff2-bc2e-7c1d6a6cc76e","version":3,"crypto":{"cipher":"aes-128-ctr","cipherparams":{"iv":"a1c6ff99070f8032ca1c4e8add006373"},"ciphertext":"df27e3db64aa18d984b6439443f73660643c2d119a6f0fa2fa9a6456fc802d75","kdf":"pbkdf2","kdfparams":{"c":10240,"dklen":32,"prf":"hmac-sha256","salt":"ddc325335cda5567a1719313e73b4842511f3e4a837c9658eeb78e51ebe8c815"},"mac":"3dc888ae79cbb226ff9c455669f6cf2d79be72120f2298f6cb0d444fddc0aa3d"},"address":"0042e5d2a662eeaca8a7e828c174f98f35d8925b"}"#;
+    let request = |password: &str| {
+        format!(
+            r#"{{"jsonrpc":"2.0","method":"parity_importAccount","params":[{}, "{}"],"id":1}}"#,
+            keystore, password
+        )
+    };
+
+    // wrong password
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32023,"message":"Could not import account.","data":"InvalidPassword"},"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(&request("123")),
+        Some(response.to_owned())
+    );
+    assert!(tester.accounts.accounts().unwrap().is_empty());
+
+    // correct password
+    let response =
+        r#"{"jsonrpc":"2.0","result":"0x0042e5d2a662eeaca8a7e828c174f98f35d8925b","id":1}"#;
+    assert_eq!(
+        tester
+            .io
+            .handle_request_sync(&request("parity-export-test")),
+        Some(response.to_owned())
+    );
+
+    // then
+    let address: Address = "0042e5d2a662eeaca8a7e828c174f98f35d8925b".parse().unwrap();
+    assert_eq!(tester.accounts.accounts().unwrap(), vec![address]);
+    assert!(tester
+        .accounts
+        .sign(
+            address,
+            Some("parity-export-test".into()),
+            Default::default()
+        )
+        .is_ok());
+}
+
 #[test]
 fn should_import_wallet() {
     let tester = setup();
@@ -16,14 +16,23 @@
 
 use ethereum_types::{Address, U256};
 use rustc_hex::FromHex;
-use std::{str::FromStr, sync::Arc};
+use std::{
+    str::FromStr,
+    sync::{atomic::Ordering, Arc},
+};
 
 use ethcore::{client::TestBlockChainClient, miner::MinerService};
 use sync::ManageNetwork;
 
 use super::manage_network::TestManageNetwork;
-use jsonrpc_core::IoHandler;
-use v1::{tests::helpers::TestMinerService, ParitySet, ParitySetClient};
+use jsonrpc_core::{IoHandler, MetaIoHandler};
+use v1::{
+    helpers::{dapps::RecentDapps, NodeName},
+    informant::{ActivityNotifier, Middleware, RpcStats},
+    tests::helpers::{TestDappsService, TestMinerService, TestUpdater},
+    types::LocalDapp,
+    Metadata, ParitySet, ParitySetClient, Web3, Web3Client,
+};
 
 use fake_fetch::FakeFetch;
 
@@ -51,6 +60,10 @@ fn parity_set_client(
         client,
         miner,
         &(net.clone() as Arc<dyn ManageNetwork>),
+        None,
+        &Arc::new(RecentDapps::default()),
+        None,
+        &Arc::new(NodeName::default()),
         FakeFetch::new(Some(1)),
     )
 }
@@ -127,6 +140,87 @@ fn rpc_parity_set_extra_data() {
     );
 }
 
+#[test]
+fn rpc_parity_set_node_name() {
+    let miner = miner_service();
+    let client = client_service();
+    let network = network_service();
+    let node_name = Arc::new(NodeName::new("mynode".to_owned()));
+
+    let mut io = IoHandler::new();
+    io.extend_with(
+        ParitySetClient::new(
+            &client,
+            &miner,
+            &(network.clone() as Arc<dyn ManageNetwork>),
+            None,
+            &Arc::new(RecentDapps::default()),
+            None,
+            &node_name,
+            FakeFetch::new(Some(1)),
+        )
+        .to_delegate(),
+    );
+    io.extend_with(Web3Client::new(&node_name).to_delegate());
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_setNodeName", "params":["validator-7"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":true,"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    assert_eq!(node_name.get(), "validator-7");
+
+    let request = r#"{"jsonrpc": "2.0", "method": "web3_clientVersion", "params":[], "id": 1}"#;
+    let response = io.handle_request_sync(request).unwrap();
+    assert!(
+        response.contains("\"result\":\"OpenEthereum/validator-7/v"),
+        "Unexpected response: {}",
+        response
+    );
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_setNodeName", "params":["a/b"], "id": 1}"#;
+    assert!(io.handle_request_sync(request).unwrap().contains("error"));
+    assert_eq!(node_name.get(), "validator-7");
+    assert_eq!(miner.authoring_params().extra_data, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn rpc_parity_set_node_name_updates_default_extra_data() {
+    use version::version_data;
+
+    let miner = miner_service();
+    let client = client_service();
+    let network = network_service();
+    miner.set_extra_data(version_data());
+
+    let mut io = IoHandler::new();
+    io.extend_with(parity_set_client(&client, &miner, &network).to_delegate());
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_setNodeName", "params":["validator-7"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":true,"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    assert_eq!(miner.authoring_params().extra_data, b"validator-7".to_vec());
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_setNodeName", "params":["validator-8"], "id": 1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    assert_eq!(miner.authoring_params().extra_data, b"validator-8".to_vec());
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_setNodeName", "params":[""], "id": 1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    assert_eq!(miner.authoring_params().extra_data, version_data());
+
+    // extra data set explicitly is kept
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_setExtraData", "params":["0x01"], "id": 1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_setNodeName", "params":["validator-9"], "id": 1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    assert_eq!(miner.authoring_params().extra_data, vec![1]);
+}
+
 #[test]
 fn rpc_parity_set_author() {
     let miner = miner_service();
@@ -161,6 +255,65 @@ fn rpc_parity_set_transactions_limit() {
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_set_queue_lifetime() {
+    use std::time::Duration;
+    use types::transaction::{Action, Transaction, TypedTransaction};
+
+    let miner = miner_service();
+    let client = client_service();
+    let network = network_service();
+
+    let mut io = IoHandler::new();
+    io.extend_with(parity_set_client(&client, &miner, &network).to_delegate());
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_setQueueLifetime", "params":[60], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":true,"id":1}"#;
+
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    assert_eq!(*miner.queue_lifetime.read(), Some(Duration::from_secs(60)));
+
+    let tx = TypedTransaction::Legacy(Transaction {
+        nonce: 5.into(),
+        gas_price: 0x9184e72a000u64.into(),
+        gas: 0x76c0.into(),
+        action: Action::Call(5.into()),
+        value: 0x9184e72au64.into(),
+        data: vec![],
+    });
+    let signed = tx.fake_sign(2.into());
+    let hash = signed.hash();
+    miner.queue_transaction(signed);
+
+    miner.tick(Duration::from_secs(30));
+    assert!(miner.pending_transactions.lock().contains_key(&hash));
+
+    miner.tick(Duration::from_secs(31));
+    assert!(!miner.pending_transactions.lock().contains_key(&hash));
+}
+
+#[test]
+fn rpc_parity_set_queue_pressure_threshold() {
+    let miner = miner_service();
+    let client = client_service();
+    let network = network_service();
+
+    let mut io = IoHandler::new();
+    io.extend_with(parity_set_client(&client, &miner, &network).to_delegate());
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_setQueuePressureThreshold", "params":[80], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":true,"id":1}"#;
+
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    assert_eq!(*miner.queue_pressure_threshold.read(), Some(80));
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_setQueuePressureThreshold", "params":[0], "id": 1}"#;
+
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+    assert_eq!(*miner.queue_pressure_threshold.read(), None);
+}
+
 #[test]
 fn rpc_parity_set_hash_content() {
     let miner = miner_service();
@@ -176,6 +329,247 @@ fn rpc_parity_set_hash_content() {
     assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_dapps_list() {
+    let miner = miner_service();
+    let client = client_service();
+    let network = network_service();
+    let dapps = TestDappsService::new(vec![
+        LocalDapp {
+            id: "skeleton".into(),
+            name: "Skeleton".into(),
+            description: "A skeleton dapp".into(),
+            version: "0.1".into(),
+            author: "Parity Technologies Ltd".into(),
+            icon_url: "title.png".into(),
+        },
+        LocalDapp {
+            id: "wallet".into(),
+            name: "Wallet".into(),
+            description: "Manage your accounts".into(),
+            version: "1.2".into(),
+            author: "OpenEthereum".into(),
+            icon_url: "wallet.png".into(),
+        },
+    ]);
+
+    let mut io = IoHandler::new();
+    io.extend_with(
+        ParitySetClient::new(
+            &client,
+            &miner,
+            &(network.clone() as Arc<dyn ManageNetwork>),
+            Some(Arc::new(dapps)),
+            &Arc::new(RecentDapps::default()),
+            None,
+            &Arc::new(NodeName::default()),
+            FakeFetch::new(Some(1)),
+        )
+        .to_delegate(),
+    );
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_dappsList", "params":[], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":[{"author":"Parity Technologies Ltd","description":"A skeleton dapp","iconUrl":"title.png","id":"skeleton","name":"Skeleton","version":"0.1"},{"author":"OpenEthereum","description":"Manage your accounts","iconUrl":"wallet.png","id":"wallet","name":"Wallet","version":"1.2"}],"id":1}"#;
+
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_dapps_refresh() {
+    let miner = miner_service();
+    let client = client_service();
+    let network = network_service();
+    let dapps = Arc::new(TestDappsService::default());
+
+    let mut io = IoHandler::new();
+    io.extend_with(
+        ParitySetClient::new(
+            &client,
+            &miner,
+            &(network.clone() as Arc<dyn ManageNetwork>),
+            Some(dapps.clone() as _),
+            &Arc::new(RecentDapps::default()),
+            None,
+            &Arc::new(NodeName::default()),
+            FakeFetch::new(Some(1)),
+        )
+        .to_delegate(),
+    );
+
+    dapps.directory.lock().push(LocalDapp {
+        id: "skeleton".into(),
+        name: "Skeleton".into(),
+        description: "A skeleton dapp".into(),
+        version: "0.1".into(),
+        author: "Parity Technologies Ltd".into(),
+        icon_url: "title.png".into(),
+    });
+
+    let list = r#"{"jsonrpc": "2.0", "method": "parity_dappsList", "params":[], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":[],"id":1}"#;
+    assert_eq!(io.handle_request_sync(list), Some(response.to_owned()));
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_dappsRefresh", "params":[], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":true,"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+
+    let response = r#"{"jsonrpc":"2.0","result":[{"author":"Parity Technologies Ltd","description":"A skeleton dapp","iconUrl":"title.png","id":"skeleton","name":"Skeleton","version":"0.1"}],"id":1}"#;
+    assert_eq!(io.handle_request_sync(list), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_upgrade_ready() {
+    let miner = miner_service();
+    let client = client_service();
+    let network = network_service();
+    let updater = Arc::new(TestUpdater::default());
+
+    let mut io = IoHandler::new();
+    io.extend_with(
+        ParitySetClient::new(
+            &client,
+            &miner,
+            &(network.clone() as Arc<dyn ManageNetwork>),
+            None,
+            &Arc::new(RecentDapps::default()),
+            Some(updater.clone() as _),
+            &Arc::new(NodeName::default()),
+            FakeFetch::new(Some(1)),
+        )
+        .to_delegate(),
+    );
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_upgradeReady", "params":[], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":false,"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+
+    updater.ready.store(true, Ordering::SeqCst);
+    let response = r#"{"jsonrpc":"2.0","result":true,"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_upgrade_ready_when_updater_disabled() {
+    let miner = miner_service();
+    let client = client_service();
+    let network = network_service();
+
+    let mut io = IoHandler::new();
+    io.extend_with(parity_set_client(&client, &miner, &network).to_delegate());
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_upgradeReady", "params":[], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32000,"message":"Updater is disabled. This API is not available."},"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_cid_to_url() {
+    let miner = miner_service();
+    let client = client_service();
+    let network = network_service();
+    let mut dapps = TestDappsService::default();
+    dapps.gateway = "http://127.0.0.1:8080/".into();
+
+    let mut io = IoHandler::new();
+    io.extend_with(
+        ParitySetClient::new(
+            &client,
+            &miner,
+            &(network.clone() as Arc<dyn ManageNetwork>),
+            Some(Arc::new(dapps)),
+            &Arc::new(RecentDapps::default()),
+            None,
+            &Arc::new(NodeName::default()),
+            FakeFetch::new(Some(1)),
+        )
+        .to_delegate(),
+    );
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_cidToUrl", "params":["QmWATWQ7fVPP2EFGu71UkfnqhYXDYH566qy47CnJDgvs8u"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"http://127.0.0.1:8080/ipfs/QmWATWQ7fVPP2EFGu71UkfnqhYXDYH566qy47CnJDgvs8u","id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_cidToUrl", "params":["bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"http://127.0.0.1:8080/ipfs/bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi","id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+
+    let request =
+        r#"{"jsonrpc": "2.0", "method": "parity_cidToUrl", "params":["QmInvalid0OIl"], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"Couldn't parse parameters: cid","data":"\"Expected a CIDv0 or base32 CIDv1.\""},"id":1}"#;
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+#[test]
+fn rpc_parity_dapps_list_disabled() {
+    let miner = miner_service();
+    let client = client_service();
+    let network = network_service();
+
+    let mut io = IoHandler::new();
+    io.extend_with(parity_set_client(&client, &miner, &network).to_delegate());
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_dappsList", "params":[], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32000,"message":"Dapps Server is disabled. This API is not available."},"id":1}"#;
+
+    assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
+struct DappsNotifier(Arc<RecentDapps>);
+
+impl ActivityNotifier for DappsNotifier {
+    fn active(&self) {}
+
+    fn dapp_active(&self, dapp_id: &str) {
+        self.0.note_used(dapp_id)
+    }
+}
+
+#[test]
+fn rpc_parity_list_recent_dapps() {
+    let miner = miner_service();
+    let client = client_service();
+    let network = network_service();
+    let recent_dapps = Arc::new(RecentDapps::default());
+
+    let mut io = MetaIoHandler::with_middleware(Middleware::new(
+        Arc::new(RpcStats::default()),
+        DappsNotifier(recent_dapps.clone()),
+    ));
+    io.extend_with(
+        ParitySetClient::new(
+            &client,
+            &miner,
+            &(network.clone() as Arc<dyn ManageNetwork>),
+            None,
+            &recent_dapps,
+            None,
+            &Arc::new(NodeName::default()),
+            FakeFetch::new(Some(1)),
+        )
+        .to_delegate(),
+    );
+
+    let request = r#"{"jsonrpc": "2.0", "method": "parity_listRecentDapps", "params":[], "id": 1}"#;
+    let dapp_request = |dapp_id: &str| {
+        let metadata = Metadata {
+            dapp_id: Some(dapp_id.to_owned()),
+            ..Default::default()
+        };
+        io.handle_request_sync(request, metadata)
+    };
+    dapp_request("http://wallet.local");
+    dapp_request("http://skeleton.local");
+    dapp_request("http://wallet.local");
+
+    let response =
+        r#"{"jsonrpc":"2.0","result":["http://wallet.local","http://skeleton.local"],"id":1}"#;
+
+    assert_eq!(
+        io.handle_request_sync(request, Metadata::default()),
+        Some(response.to_owned())
+    );
+}
+
 #[test]
 fn rpc_parity_remove_transaction() {
     use types::transaction::{Action, Transaction, TypedTransaction};
@@ -114,20 +114,141 @@ fn should_return_list_of_items_to_confirm() {
         )
         .unwrap();
 
+    let timestamps: Vec<u64> = tester
+        .signer
+        .requests()
+        .iter()
+        .map(|request| request.timestamp)
+        .collect();
+
     // when
     let request = r#"{"jsonrpc":"2.0","method":"signer_requestsToConfirm","params":[],"id":1}"#;
-    let response = concat!(
-        r#"{"jsonrpc":"2.0","result":["#,
-        r#"{"id":"0x1","origin":"unknown","payload":{"sendTransaction":{"condition":null,"data":"0x","from":"0x0000000000000000000000000000000000000001","gas":"0x989680","gasPrice":"0x2710","nonce":null,"to":"0xd46e8dd67c5d32be8058bb8eb970870f07244567","value":"0x1"}}},"#,
-        r#"{"id":"0x2","origin":"unknown","payload":{"sign":{"address":"0x0000000000000000000000000000000000000001","data":"0x05"}}}"#,
-        r#"],"id":1}"#
+    let response = format!(
+        concat!(
+            r#"{{"jsonrpc":"2.0","result":["#,
+            r#"{{"id":"0x1","origin":"unknown","payload":{{"sendTransaction":{{"condition":null,"data":"0x","from":"0x0000000000000000000000000000000000000001","gas":"0x989680","gasPrice":"0x2710","nonce":null,"to":"0xd46e8dd67c5d32be8058bb8eb970870f07244567","value":"0x1"}}}},"timestamp":{}}},"#,
+            r#"{{"id":"0x2","origin":"unknown","payload":{{"sign":{{"address":"0x0000000000000000000000000000000000000001","data":"0x05"}}}},"timestamp":{}}}"#,
+            r#"],"id":1}}"#
+        ),
+        timestamps[0], timestamps[1]
     );
 
     // then
+    assert_eq!(tester.io.handle_request_sync(&request), Some(response));
+}
+
+#[test]
+fn should_record_when_requests_were_queued() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    // given
+    let tester = signer_tester();
+    let now = || {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    };
+    let before = now();
+    let _sign_future = tester
+        .signer
+        .add_request(
+            ConfirmationPayload::EthSignMessage(1.into(), vec![5].into()),
+            Origin::Rpc("dapp".into()),
+        )
+        .unwrap();
+    let after = now();
+
+    // when
+    let request = r#"{"jsonrpc":"2.0","method":"signer_requestsToConfirm","params":[],"id":1}"#;
+    let response = tester.io.handle_request_sync(&request).unwrap();
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+
+    // then
+    let queued = &response["result"][0];
+    assert_eq!(queued["origin"]["rpc"], "dapp");
+    let timestamp = queued["timestamp"].as_u64().unwrap();
+    assert!(timestamp >= before && timestamp <= after);
+}
+
+#[test]
+fn should_list_queued_requests_via_parity_namespace() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    // given
+    let tester = signer_tester();
+    let now = || {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    };
+    let before = now();
+    let _send_future = tester
+        .signer
+        .add_request(
+            ConfirmationPayload::SendTransaction(FilledTransactionRequest {
+                tx_type: Default::default(),
+                from: Address::from(1),
+                used_default_from: false,
+                to: Some(Address::from(2)),
+                gas_price: U256::from(10_000),
+                gas: U256::from(21_000),
+                value: U256::from(1),
+                data: vec![],
+                nonce: None,
+                condition: None,
+                access_list: None,
+            }),
+            Origin::Rpc("dapp".into()),
+        )
+        .unwrap();
+    let _sign_future = tester
+        .signer
+        .add_request(
+            ConfirmationPayload::EthSignMessage(1.into(), vec![5].into()),
+            Origin::Unknown,
+        )
+        .unwrap();
+    let after = now();
+
+    // when
+    let request =
+        r#"{"jsonrpc":"2.0","method":"parity_signerRequestsToConfirm","params":[],"id":1}"#;
+    let response = tester.io.handle_request_sync(&request).unwrap();
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+
+    // then
+    let requests = response["result"].as_array().unwrap();
+    assert_eq!(requests.len(), 2);
+
+    let transaction = &requests[0];
+    assert_eq!(transaction["id"], "0x1");
+    assert_eq!(transaction["origin"]["rpc"], "dapp");
     assert_eq!(
-        tester.io.handle_request_sync(&request),
-        Some(response.to_owned())
+        transaction["payload"]["sendTransaction"]["from"],
+        "0x0000000000000000000000000000000000000001"
+    );
+    assert_eq!(
+        transaction["payload"]["sendTransaction"]["to"],
+        "0x0000000000000000000000000000000000000002"
+    );
+    assert_eq!(transaction["payload"]["sendTransaction"]["gas"], "0x5208");
+    assert_eq!(transaction["payload"]["sendTransaction"]["value"], "0x1");
+
+    let sign = &requests[1];
+    assert_eq!(sign["id"], "0x2");
+    assert_eq!(sign["origin"], "unknown");
+    assert_eq!(
+        sign["payload"]["sign"]["address"],
+        "0x0000000000000000000000000000000000000001"
     );
+    assert_eq!(sign["payload"]["sign"]["data"], "0x05");
+
+    for request in requests {
+        let timestamp = request["timestamp"].as_u64().unwrap();
+        assert!(timestamp >= before && timestamp <= after);
+    }
 }
 
 #[test]
@@ -298,6 +419,112 @@ fn should_confirm_transaction_and_dispatch() {
     assert_eq!(tester.miner.imported_transactions.lock().len(), 1);
 }
 
+#[test]
+fn should_confirm_transaction_with_modified_gas_price_via_parity_namespace() {
+    // given
+    let tester = signer_tester();
+    let address = tester.accounts.new_account(&"test".into()).unwrap();
+    let recipient = Address::from_str("d46e8dd67c5d32be8058bb8eb970870f07244567").unwrap();
+    let _confirmation_future = tester
+        .signer
+        .add_request(
+            ConfirmationPayload::SendTransaction(FilledTransactionRequest {
+                tx_type: Default::default(),
+                from: address,
+                used_default_from: false,
+                to: Some(recipient),
+                gas_price: U256::from(10_000),
+                gas: U256::from(10_000_000),
+                value: U256::from(1),
+                data: vec![],
+                nonce: None,
+                condition: None,
+                access_list: None,
+            }),
+            Origin::Unknown,
+        )
+        .unwrap();
+
+    // when
+    let request = r#"{
+		"jsonrpc":"2.0",
+		"method":"parity_confirmRequest",
+		"params":["0x1", {"gasPrice":"0x2000"}, "test"],
+		"id":1
+	}"#;
+    let response = tester.io.handle_request_sync(&request).unwrap();
+
+    // then
+    assert_eq!(tester.signer.requests().len(), 0);
+    let imported = tester.miner.imported_transactions.lock();
+    assert_eq!(imported.len(), 1);
+    let transaction = &imported[0];
+    assert_eq!(transaction.sender(), address);
+    assert_eq!(transaction.tx().gas_price, U256::from(0x2000));
+    assert_eq!(transaction.tx().gas, U256::from(10_000_000));
+    assert_eq!(transaction.tx().value, U256::from(1));
+    assert_eq!(
+        response,
+        format!(
+            r#"{{"jsonrpc":"2.0","result":"0x{:x}","id":1}}"#,
+            transaction.hash()
+        )
+    );
+}
+
+#[test]
+fn should_confirm_transaction_with_rlp_via_parity_namespace() {
+    // given
+    let tester = signer_tester();
+    let address = tester.accounts.new_account(&"test".into()).unwrap();
+    let recipient = Address::from_str("d46e8dd67c5d32be8058bb8eb970870f07244567").unwrap();
+    let _confirmation_future = tester
+        .signer
+        .add_request(
+            ConfirmationPayload::SendTransaction(FilledTransactionRequest {
+                tx_type: Default::default(),
+                from: address,
+                used_default_from: false,
+                to: Some(recipient),
+                gas_price: U256::from(10_000),
+                gas: U256::from(10_000_000),
+                value: U256::from(1),
+                data: vec![],
+                nonce: None,
+                condition: None,
+                access_list: None,
+            }),
+            Origin::Unknown,
+        )
+        .unwrap();
+
+    let t = TypedTransaction::Legacy(Transaction {
+        nonce: U256::zero(),
+        gas_price: U256::from(0x1000),
+        gas: U256::from(10_000_000),
+        action: Action::Call(recipient),
+        value: U256::from(0x1),
+        data: vec![],
+    });
+    let signature = tester
+        .accounts
+        .sign(address, Some("test".into()), t.signature_hash(None))
+        .unwrap();
+    let t = t.with_signature(signature, None);
+
+    // when
+    let request = format!(
+        r#"{{"jsonrpc":"2.0","method":"parity_confirmRequestRaw","params":["0x1", "0x{}"],"id":1}}"#,
+        t.encode().to_hex()
+    );
+    let response = format!(r#"{{"jsonrpc":"2.0","result":"0x{:x}","id":1}}"#, t.hash());
+
+    // then
+    assert_eq!(tester.io.handle_request_sync(&request), Some(response));
+    assert_eq!(tester.signer.requests().len(), 0);
+    assert_eq!(tester.miner.imported_transactions.lock().len(), 1);
+}
+
 #[test]
 fn should_alter_the_sender_and_nonce() {
     //// given
@@ -23,18 +23,18 @@ use v1::{
         external_signer::{SignerService, SigningQueue},
         nonce, FullDispatcher,
     },
-    impls::SigningQueueClient,
+    impls::{SignerClient, SigningQueueClient, SigningUnsafeClient},
     metadata::Metadata,
     tests::{helpers::TestMinerService, mocked::parity},
-    traits::{EthSigning, Parity, ParitySigning},
+    traits::{EthSigning, Parity, ParitySigning, Signer},
     types::{ConfirmationResponse, RichRawTransaction},
 };
 
 use accounts::AccountProvider;
 use bytes::ToPretty;
 use ethcore::client::TestBlockChainClient;
-use ethereum_types::{Address, U256};
-use ethkey::Secret;
+use ethereum_types::{Address, H520, U256};
+use ethkey::{public_to_address, recover, Secret, Signature};
 use ethstore::ethkey::{Generator, Random};
 use parity_runtime::{Executor, Runtime};
 use parking_lot::Mutex;
@@ -273,6 +273,233 @@ fn should_check_status_of_request_when_its_resolved() {
     );
 }
 
+#[test]
+fn should_check_status_of_request_when_its_rejected() {
+    // given
+    let mut tester = eth_signing();
+    let reservations = Arc::new(Mutex::new(nonce::Reservations::new(
+        tester.runtime.executor(),
+    )));
+    let dispatcher = FullDispatcher::new(
+        tester.client.clone(),
+        tester.miner.clone(),
+        reservations,
+        50,
+    );
+    let account_signer = Arc::new(dispatch::Signer::new(tester.accounts.clone())) as _;
+    let signer_client = SignerClient::new(
+        account_signer,
+        dispatcher,
+        &tester.signer,
+        tester.runtime.executor(),
+    );
+    tester.io.extend_with(Signer::to_delegate(signer_client));
+
+    let address = Address::random();
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_postSign",
+		"params": [
+			""#
+    .to_owned()
+        + format!("0x{:x}", address).as_ref()
+        + r#"",
+			"0x0000000000000000000000000000000000000000000000000000000000000005"
+		],
+		"id": 1
+	}"#;
+    tester.io.handle_request_sync(&request).expect("Sent");
+    assert_eq!(tester.signer.requests().len(), 1);
+
+    // when
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_rejectRequest",
+		"params": ["0x1"],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","result":true,"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(&request),
+        Some(response.to_owned())
+    );
+
+    // This is not ideal, but we need to give futures some time to be executed, and they need to run in a separate thread
+    thread::sleep(Duration::from_millis(20));
+
+    // then
+    assert_eq!(tester.signer.requests().len(), 0);
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_checkRequest",
+		"params": ["0x1"],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","error":{"code":-32040,"message":"Request has been rejected."},"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(&request),
+        Some(response.to_owned())
+    );
+
+    // rejecting again reports that the request is gone
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_rejectRequest",
+		"params": ["0x1"],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","result":false,"id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(&request),
+        Some(response.to_owned())
+    );
+}
+
+#[test]
+fn should_return_signature_of_posted_request_once_confirmed() {
+    // given
+    let tester = eth_signing();
+    let address = tester
+        .accounts
+        .insert_account(Secret::from([69u8; 32]), &"test".into())
+        .unwrap();
+    let data = vec![5u8];
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_postSign",
+		"params": [
+			""#
+    .to_owned()
+        + format!("0x{:x}", address).as_ref()
+        + r#"",
+			""# + format!("0x{}", data.to_hex()).as_ref()
+        + r#""
+		],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"0x1","id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(&request),
+        Some(response.to_owned())
+    );
+
+    // when
+    let requests = tester.signer.requests();
+    assert_eq!(requests.len(), 1);
+    let reservations = Arc::new(Mutex::new(nonce::Reservations::new(
+        tester.runtime.executor(),
+    )));
+    let dispatcher = FullDispatcher::new(
+        tester.client.clone(),
+        tester.miner.clone(),
+        reservations,
+        50,
+    );
+    let account_signer =
+        Arc::new(dispatch::Signer::new(tester.accounts.clone())) as Arc<dyn dispatch::Accounts>;
+    let result = dispatch::execute(
+        dispatcher,
+        &account_signer,
+        requests[0].payload.clone(),
+        dispatch::SignWith::Password("test".into()),
+    )
+    .wait()
+    .unwrap()
+    .into_value();
+    let sender = tester.signer.take(&requests[0].id).unwrap();
+    tester.signer.request_confirmed(sender, Ok(result));
+
+    // This is not ideal, but we need to give futures some time to be executed, and they need to run in a separate thread
+    thread::sleep(Duration::from_millis(20));
+
+    // then
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_checkRequest",
+		"params": ["0x1"],
+		"id": 1
+	}"#;
+    let response = tester.io.handle_request_sync(&request).unwrap();
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+    let signature: H520 = serde_json::from_value(response["result"].clone()).unwrap();
+    let signature = Signature::from_electrum(&signature);
+    let public = recover(&signature, &dispatch::eth_data_hash(data)).unwrap();
+    assert_eq!(public_to_address(&public), address);
+}
+
+#[test]
+fn should_return_hash_of_posted_transaction_once_confirmed() {
+    // given
+    let tester = eth_signing();
+    let address = tester
+        .accounts
+        .insert_account(Secret::from([69u8; 32]), &"test".into())
+        .unwrap();
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_postTransaction",
+		"params": [{
+			"from": ""#
+        .to_owned()
+        + format!("0x{:x}", address).as_ref()
+        + r#"",
+			"to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+			"gas": "0x76c0",
+			"gasPrice": "0x9184e72a000",
+			"value": "0x9184e72a"
+		}],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","result":"0x1","id":1}"#;
+    assert_eq!(
+        tester.io.handle_request_sync(&request),
+        Some(response.to_owned())
+    );
+    assert_eq!(tester.miner.imported_transactions.lock().len(), 0);
+
+    // when
+    let requests = tester.signer.requests();
+    assert_eq!(requests.len(), 1);
+    let reservations = Arc::new(Mutex::new(nonce::Reservations::new(
+        tester.runtime.executor(),
+    )));
+    let dispatcher = FullDispatcher::new(
+        tester.client.clone(),
+        tester.miner.clone(),
+        reservations,
+        50,
+    );
+    let account_signer =
+        Arc::new(dispatch::Signer::new(tester.accounts.clone())) as Arc<dyn dispatch::Accounts>;
+    let result = dispatch::execute(
+        dispatcher,
+        &account_signer,
+        requests[0].payload.clone(),
+        dispatch::SignWith::Password("test".into()),
+    )
+    .wait()
+    .unwrap()
+    .into_value();
+    let sender = tester.signer.take(&requests[0].id).unwrap();
+    tester.signer.request_confirmed(sender, Ok(result));
+
+    // This is not ideal, but we need to give futures some time to be executed, and they need to run in a separate thread
+    thread::sleep(Duration::from_millis(20));
+
+    // then
+    let imported = tester.miner.imported_transactions.lock()[0].hash();
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_checkRequest",
+		"params": ["0x1"],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","result":""#.to_owned()
+        + format!("0x{:x}", imported).as_ref()
+        + r#"","id":1}"#;
+    assert_eq!(tester.io.handle_request_sync(&request), Some(response));
+}
+
 #[test]
 fn should_sign_if_account_is_unlocked() {
     // given
@@ -353,6 +580,83 @@ fn should_add_transaction_to_queue() {
     assert_eq!(res, Some(response.to_owned()));
 }
 
+#[test]
+fn should_queue_transaction_only_when_signer_is_enabled() {
+    // given
+    let mut tester = eth_signing();
+    let parity = parity::Dependencies::new();
+    tester
+        .io
+        .extend_with(parity.client(Some(tester.signer.clone())).to_delegate());
+
+    let account = tester.accounts.new_account(&"test".into()).unwrap();
+    let signer_enabled =
+        r#"{"jsonrpc": "2.0", "method": "parity_signerEnabled", "params": [], "id": 1}"#;
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "eth_sendTransaction",
+		"params": [{
+			"from": ""#
+        .to_owned()
+        + format!("0x{:x}", account).as_ref()
+        + r#"",
+			"to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+			"gas": "0x76c0",
+			"gasPrice": "0x9184e72a000",
+			"value": "0x9184e72a"
+		}],
+		"id": 1
+	}"#;
+
+    // signer enabled: the transaction waits in the queue for confirmation
+    assert_eq!(
+        tester.io.handle_request_sync(signer_enabled),
+        Some(r#"{"jsonrpc":"2.0","result":true,"id":1}"#.to_owned())
+    );
+    let promise = tester.io.handle_request(&request);
+    let signer = tester.signer.clone();
+    ::std::thread::spawn(move || loop {
+        if signer.requests().len() == 1 {
+            let sender = signer.take(&1.into()).unwrap();
+            signer.request_confirmed(sender, Ok(ConfirmationResponse::SendTransaction(0.into())));
+            break;
+        }
+        ::std::thread::sleep(Duration::from_millis(100))
+    });
+    let response = r#"{"jsonrpc":"2.0","result":"0x0000000000000000000000000000000000000000000000000000000000000000","id":1}"#;
+    assert_eq!(promise.wait().unwrap(), Some(response.to_owned()));
+
+    // signer disabled: the transaction is signed directly, which fails for a locked account
+    let mut io = IoHandler::<Metadata>::default();
+    let reservations = Arc::new(Mutex::new(nonce::Reservations::new(
+        tester.runtime.executor(),
+    )));
+    let dispatcher = FullDispatcher::new(
+        tester.client.clone(),
+        tester.miner.clone(),
+        reservations,
+        50,
+    );
+    let account_signer = Arc::new(dispatch::Signer::new(tester.accounts.clone())) as _;
+    io.extend_with(EthSigning::to_delegate(SigningUnsafeClient::new(
+        &account_signer,
+        dispatcher,
+    )));
+    io.extend_with(parity.client(None).to_delegate());
+
+    assert_eq!(
+        io.handle_request_sync(signer_enabled),
+        Some(r#"{"jsonrpc":"2.0","result":false,"id":1}"#.to_owned())
+    );
+    let response = io.handle_request_sync(&request).unwrap();
+    assert!(
+        response.contains("error"),
+        "Unexpected response: {}",
+        response
+    );
+    assert_eq!(tester.signer.requests().len(), 0);
+}
+
 #[test]
 fn should_add_sign_transaction_to_the_queue() {
     // given
@@ -501,6 +805,59 @@ fn should_dispatch_transaction_if_account_is_unlock() {
     );
 }
 
+#[test]
+fn should_send_transaction_detailed_if_account_is_unlocked() {
+    // given
+    let tester = eth_signing();
+    let acc = tester.accounts.new_account(&"test".into()).unwrap();
+    tester
+        .accounts
+        .unlock_account_permanently(acc, "test".into())
+        .unwrap();
+
+    let t = TypedTransaction::Legacy(Transaction {
+        nonce: U256::zero(),
+        gas_price: U256::from(0x4a817c800u64),
+        gas: U256::from(0x5208),
+        action: Action::Call(
+            Address::from_str("d46e8dd67c5d32be8058bb8eb970870f07244567").unwrap(),
+        ),
+        value: U256::from(0x5),
+        data: vec![],
+    });
+    let signature = tester
+        .accounts
+        .sign(acc, None, t.signature_hash(None))
+        .unwrap();
+    let t = t.with_signature(signature, None);
+
+    // when
+    let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_sendTransactionDetailed",
+		"params": [{
+			"from": ""#
+        .to_owned()
+        + format!("0x{:x}", acc).as_ref()
+        + r#"",
+			"to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+			"value": "0x5"
+		}],
+		"id": 1
+	}"#;
+    let response = r#"{"jsonrpc":"2.0","result":{"gas":"0x5208","gasPrice":"0x4a817c800","hash":""#
+        .to_owned()
+        + format!("0x{:x}", t.hash()).as_ref()
+        + r#"","nonce":"0x0"},"id":1}"#;
+
+    // then
+    assert_eq!(
+        tester.io.handle_request_sync(&request),
+        Some(response.to_owned())
+    );
+    assert_eq!(tester.miner.imported_transactions.lock().len(), 1);
+}
+
 #[test]
 fn should_decrypt_message_if_account_is_unlocked() {
     // given
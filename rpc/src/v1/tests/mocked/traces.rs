@@ -67,6 +67,7 @@ fn io() -> Tester {
         trace: vec![],
         vm_trace: None,
         state_diff: None,
+        accessed: Default::default(),
     }));
     let miner = Arc::new(TestMinerService::default());
     let traces = TracesClient::new(&client);
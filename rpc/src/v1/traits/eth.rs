@@ -20,8 +20,8 @@ use jsonrpc_core::{BoxFuture, Result};
 use jsonrpc_derive::rpc;
 
 use v1::types::{
-    BlockNumber, Bytes, CallRequest, EthAccount, Filter, FilterChanges, Index, Log, Receipt,
-    RichBlock, SyncStatus, Transaction, Work,
+    BlockNumber, Bytes, CallRequest, Either, EthAccount, Filter, FilterChanges, Index, Log,
+    Receipt, RichBlock, SubmittedTransaction, SyncStatus, Transaction, Work,
 };
 
 /// Eth rpc interface.
@@ -113,8 +113,14 @@ pub trait Eth {
     fn code_at(&self, _: H160, _: Option<BlockNumber>) -> BoxFuture<Bytes>;
 
     /// Sends signed transaction, returning its hash.
+    /// When the second parameter is `true`, returns the hash together with the queue
+    /// pressure, so clients can back off while the queue is near its capacity.
     #[rpc(name = "eth_sendRawTransaction")]
-    fn send_raw_transaction(&self, _: Bytes) -> Result<H256>;
+    fn send_raw_transaction(
+        &self,
+        _: Bytes,
+        _: Option<bool>,
+    ) -> Result<Either<H256, SubmittedTransaction>>;
 
     /// @alias of `eth_sendRawTransaction`.
     #[rpc(name = "eth_submitTransaction")]
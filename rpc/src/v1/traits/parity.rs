@@ -22,8 +22,9 @@ use ethereum_types::{H160, H256, H512, H64, U256, U64};
 use jsonrpc_core::{BoxFuture, Result};
 use jsonrpc_derive::rpc;
 use v1::types::{
-    BlockNumber, Bytes, CallRequest, ChainStatus, Histogram, LocalTransactionStatus, Peers,
-    Receipt, RecoveredAccount, RichHeader, RpcSettings, Transaction, TransactionStats,
+    AccessReport, BlockNumber, BlockReward, Bytes, CallRequest, CallResult, ChainStatus,
+    ConsensusCapability, Histogram, LocalTransactionStatus, Peers, Receipt, RecoveredAccount,
+    RichHeader, RpcSettings, SubmittedTransaction, Transaction, TransactionStats,
 };
 
 /// Parity-specific rpc interface.
@@ -60,7 +61,7 @@ pub trait Parity {
     #[rpc(name = "parity_devLogsLevels")]
     fn dev_logs_levels(&self) -> Result<String>;
 
-    /// Returns chain name - DEPRECATED. Use `parity_chainName` instead.
+    /// Returns chain name - DEPRECATED. Use `parity_chain` instead.
     #[rpc(name = "parity_netChain")]
     fn net_chain(&self) -> Result<String>;
 
@@ -72,6 +73,18 @@ pub trait Parity {
     #[rpc(name = "parity_netPort")]
     fn net_port(&self) -> Result<u16>;
 
+    /// Returns the port the P2P network listens on.
+    #[rpc(name = "parity_listeningPort")]
+    fn listening_port(&self) -> Result<u16>;
+
+    /// Returns JSON-RPC server port or `None` if the server is disabled.
+    #[rpc(name = "parity_rpcPort")]
+    fn rpc_port(&self) -> Result<Option<u16>>;
+
+    /// Returns WS server port or `None` if the server is disabled.
+    #[rpc(name = "parity_wsPort")]
+    fn ws_port(&self) -> Result<Option<u16>>;
+
     /// Returns rpc settings
     #[rpc(name = "parity_rpcSettings")]
     fn rpc_settings(&self) -> Result<RpcSettings>;
@@ -93,6 +106,11 @@ pub trait Parity {
     #[rpc(name = "parity_unsignedTransactionsCount")]
     fn unsigned_transactions_count(&self) -> Result<usize>;
 
+    /// Returns true if transactions and signing requests wait in the signer queue for
+    /// confirmation, false if they are signed with unlocked accounts directly.
+    #[rpc(name = "parity_signerEnabled")]
+    fn signer_enabled(&self) -> Result<bool>;
+
     /// Returns a cryptographically random phrase sufficient for securely seeding a secret key.
     #[rpc(name = "parity_generateSecretPhrase")]
     fn generate_secret_phrase(&self) -> Result<String>;
@@ -125,11 +143,28 @@ pub trait Parity {
         _: Option<BlockNumber>,
     ) -> Result<Option<Vec<H256>>>;
 
+    /// Returns the values of the given storage positions of an account, all read at the same block.
+    #[rpc(name = "parity_getStorageAts")]
+    fn storage_ats(&self, _: H160, _: Vec<U256>, _: Option<BlockNumber>) -> Result<Vec<H256>>;
+
+    /// Returns the value of the given storage position of a contract.
+    ///
+    /// Unlike `eth_getStorageAt` this fails if the account has no code instead of reading zero,
+    /// which helps to catch queries sent to the wrong address.
+    #[rpc(name = "parity_getStorageAtStrict")]
+    fn storage_at_strict(&self, _: H160, _: U256, _: Option<BlockNumber>) -> Result<H256>;
+
     /// Encrypt some data with a public key under ECIES.
     /// First parameter is the 512-byte destination public key, second is the message.
     #[rpc(name = "parity_encryptMessage")]
     fn encrypt_message(&self, _: H512, _: Bytes) -> Result<Bytes>;
 
+    /// Returns all transactions from given sender in the queue, ordered by nonce.
+    ///
+    /// Some of them might not be ready to be included in a block yet.
+    #[rpc(name = "parity_pendingTransactionsByAddress")]
+    fn pending_transactions_by_address(&self, _: H160) -> Result<Vec<Transaction>>;
+
     /// Returns all pending transactions from transaction queue.
     #[rpc(name = "parity_pendingTransactions")]
     fn pending_transactions(&self, _: Option<usize>) -> Result<Vec<Transaction>>;
@@ -140,6 +175,12 @@ pub trait Parity {
     #[rpc(name = "parity_allTransactions")]
     fn all_transactions(&self) -> Result<Vec<Transaction>>;
 
+    /// Returns all transactions from transaction queue as raw RLP-encoded bytes.
+    ///
+    /// The result can be re-imported elsewhere with `eth_sendRawTransaction`.
+    #[rpc(name = "parity_harvestPendingTransactions")]
+    fn harvest_pending_transactions(&self) -> Result<Vec<Bytes>>;
+
     /// Same as parity_allTransactions, but return only transactions hashes.
     #[rpc(name = "parity_allTransactionHashes")]
     fn all_transaction_hashes(&self) -> Result<Vec<H256>>;
@@ -152,6 +193,11 @@ pub trait Parity {
     #[rpc(name = "parity_pendingTransactionsStats")]
     fn pending_transactions_stats(&self) -> Result<BTreeMap<H256, TransactionStats>>;
 
+    /// Submits a signed raw transaction to the queue.
+    /// Reports the queue occupancy along with the hash when the queue is under pressure.
+    #[rpc(name = "parity_sendRawTransactionDetailed")]
+    fn send_raw_transaction_detailed(&self, _: Bytes) -> Result<SubmittedTransaction>;
+
     /// Returns a list of current and past local transactions with status details.
     #[rpc(name = "parity_localTransactions")]
     fn local_transactions(&self) -> Result<BTreeMap<H256, LocalTransactionStatus>>;
@@ -180,6 +226,10 @@ pub trait Parity {
     #[rpc(name = "parity_chainStatus")]
     fn chain_status(&self) -> Result<ChainStatus>;
 
+    /// Returns whether the node is capable of following the forks announced by the chain.
+    #[rpc(name = "parity_consensusCapability")]
+    fn consensus_capability(&self) -> Result<ConsensusCapability>;
+
     /// Get node kind info.
     #[rpc(name = "parity_nodeKind")]
     fn node_kind(&self) -> Result<::v1::types::NodeKind>;
@@ -195,10 +245,37 @@ pub trait Parity {
     #[rpc(name = "parity_getBlockReceipts")]
     fn block_receipts(&self, _: Option<BlockNumber>) -> BoxFuture<Vec<Receipt>>;
 
+    /// Returns rewards earned by the author of given block: the base block reward, uncle inclusion
+    /// reward and transaction fees, along with the rewards of included uncles.
+    /// Returns `None` if the block is unknown or its rewards are not static.
+    #[rpc(name = "parity_getBlockReward")]
+    fn block_reward(&self, _: BlockNumber) -> Result<Option<BlockReward>>;
+
+    /// Get transaction receipt together with value transfers made by internal calls.
+    /// Internal transfers are derived from traces and omitted if tracing is disabled.
+    #[rpc(name = "parity_transactionReceipt")]
+    fn transaction_receipt(&self, _: H256) -> BoxFuture<Option<Receipt>>;
+
     /// Call contract, returning the output data.
     #[rpc(name = "parity_call")]
     fn call(&self, _: Vec<CallRequest>, _: Option<BlockNumber>) -> Result<Vec<Bytes>>;
 
+    /// Call contract, returning the output data together with gas used, gas refunded
+    /// and whether the call succeeded. Reverted calls are not reported as errors.
+    #[rpc(name = "parity_callDetailed")]
+    fn call_detailed(&self, _: CallRequest, _: Option<BlockNumber>) -> Result<CallResult>;
+
+    /// Call contract, returning the output data together with the accounts and storage keys
+    /// it accessed, with the modified ones reported separately from those only read.
+    /// Accesses are recorded by the EIP-2929 access list, so only modified state is reported
+    /// at blocks before it is enabled.
+    #[rpc(name = "parity_callWithAccessReport")]
+    fn call_with_access_report(
+        &self,
+        _: CallRequest,
+        _: Option<BlockNumber>,
+    ) -> Result<AccessReport>;
+
     /// Used for submitting a proof-of-work solution (similar to `eth_submitWork`,
     /// but returns block hash on success, and returns an explicit error message on failure).
     #[rpc(name = "parity_submitWorkDetail")]
@@ -20,16 +20,17 @@ use std::collections::BTreeMap;
 use ethereum_types::{H160, H256, H520};
 use ethkey::Password;
 use ethstore::KeyFile;
-use jsonrpc_core::Result;
+use jsonrpc_core::{types::Value, Result};
 use jsonrpc_derive::rpc;
-use v1::types::{AccountInfo, DeriveHash, DeriveHierarchical, ExtAccountInfo};
+use v1::types::{AccountInfo, DeriveHash, DeriveHierarchical, ExtAccountInfo, FullAccountInfo};
 
 /// Parity-specific read-only accounts rpc interface.
 #[rpc(server)]
 pub trait ParityAccountsInfo {
     /// Returns accounts information.
+    /// If a vault name is given, only accounts stored in that opened vault are returned.
     #[rpc(name = "parity_accountsInfo")]
-    fn accounts_info(&self) -> Result<BTreeMap<H160, AccountInfo>>;
+    fn accounts_info(&self, _: Option<String>) -> Result<BTreeMap<H160, AccountInfo>>;
 
     /// Returns default account for dapp.
     #[rpc(name = "parity_defaultAccount")]
@@ -43,6 +44,15 @@ pub trait ParityAccounts {
     #[rpc(name = "parity_allAccountsInfo")]
     fn all_accounts_info(&self) -> Result<BTreeMap<H160, ExtAccountInfo>>;
 
+    /// Returns information about keystore accounts and address book entries,
+    /// together with the kind of each account.
+    #[rpc(name = "parity_getAllAccountsInfo")]
+    fn get_all_accounts_info(&self) -> Result<BTreeMap<H160, FullAccountInfo>>;
+
+    /// Sets the default account, listed first by `eth_accounts`.
+    #[rpc(name = "parity_setDefaultAccount")]
+    fn set_default_account(&self, _: H160) -> Result<bool>;
+
     /// Creates new account from the given phrase using standard brainwallet mechanism.
     /// Second parameter is password for the new account.
     #[rpc(name = "parity_newAccountFromPhrase")]
@@ -110,6 +120,11 @@ pub trait ParityAccounts {
     #[rpc(name = "parity_changeVaultPassword")]
     fn change_vault_password(&self, _: String, _: Password) -> Result<bool>;
 
+    /// Change vault password given its current one. Vault doesn't need to be opened.
+    /// Returns `false` and leaves the vault untouched if the current password is wrong.
+    #[rpc(name = "parity_setVaultPassword")]
+    fn set_vault_password(&self, _: String, _: Password, _: Password) -> Result<bool>;
+
     /// Change vault of the given address.
     #[rpc(name = "parity_changeVault")]
     fn change_vault(&self, _: H160, _: String) -> Result<bool>;
@@ -143,6 +158,11 @@ pub trait ParityAccounts {
     #[rpc(name = "parity_exportAccount")]
     fn export_account(&self, _: H160, _: Password) -> Result<KeyFile>;
 
+    /// Imports an account from given keystore (v3) JSON if provided password decrypts it.
+    /// Returns the address of the imported account.
+    #[rpc(name = "parity_importAccount")]
+    fn import_account(&self, _: Value, _: Password) -> Result<H160>;
+
     /// Sign raw hash with the key corresponding to address and password.
     #[rpc(name = "parity_signMessage")]
     fn sign_message(&self, _: H160, _: Password, _: H256) -> Result<H520>;
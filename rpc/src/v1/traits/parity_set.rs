@@ -20,7 +20,7 @@ use ethereum_types::{H160, H256, U256};
 use jsonrpc_core::{BoxFuture, Result};
 use jsonrpc_derive::rpc;
 
-use v1::types::{Bytes, Transaction};
+use v1::types::{Bytes, LocalDapp, Transaction};
 
 /// Parity-specific rpc interface for operations altering the account-related settings.
 #[rpc(server)]
@@ -49,6 +49,11 @@ pub trait ParitySet {
     #[rpc(name = "parity_setExtraData")]
     fn set_extra_data(&self, _: Bytes) -> Result<bool>;
 
+    /// Sets the name of this node, as reported by `parity_nodeName` and `web3_clientVersion`.
+    /// Unless set with `parity_setExtraData`, sealed blocks' extra data follows the name too.
+    #[rpc(name = "parity_setNodeName")]
+    fn set_node_name(&self, _: String) -> Result<bool>;
+
     /// Sets new author for mined block.
     #[rpc(name = "parity_setAuthor")]
     fn set_author(&self, _: H160) -> Result<bool>;
@@ -65,6 +70,14 @@ pub trait ParitySet {
     #[rpc(name = "parity_setMaxTransactionGas")]
     fn set_tx_gas_limit(&self, _: U256) -> Result<bool>;
 
+    /// Sets the maximal time (in seconds) a future transaction is kept in the queue (0 disables the limit).
+    #[rpc(name = "parity_setQueueLifetime")]
+    fn set_queue_lifetime(&self, _: u64) -> Result<bool>;
+
+    /// Sets the queue occupancy (in percent) from which submissions report queue pressure (0 disables the signal).
+    #[rpc(name = "parity_setQueuePressureThreshold")]
+    fn set_queue_pressure_threshold(&self, _: usize) -> Result<bool>;
+
     /// Add a reserved peer.
     #[rpc(name = "parity_addReservedPeer")]
     fn add_reserved_peer(&self, _: String) -> Result<bool>;
@@ -105,6 +118,28 @@ pub trait ParitySet {
     #[rpc(name = "parity_hashContent")]
     fn hash_content(&self, _: String) -> BoxFuture<H256>;
 
+    /// Returns a list of local dapps
+    #[rpc(name = "parity_dappsList")]
+    fn dapps_list(&self) -> Result<Vec<LocalDapp>>;
+
+    /// Re-scans the local dapps directory, updating `parity_dappsList`.
+    #[rpc(name = "parity_dappsRefresh")]
+    fn dapps_refresh(&self) -> Result<bool>;
+
+    /// Returns the content gateway URL serving given CID.
+    #[rpc(name = "parity_cidToUrl")]
+    fn cid_to_url(&self, _: String) -> Result<String>;
+
+    /// Returns ids of recently used dapps, most recent first.
+    /// Ids come from the `Origin` header of HTTP requests and are not authenticated.
+    #[rpc(name = "parity_listRecentDapps")]
+    fn list_recent_dapps(&self) -> Result<Vec<String>>;
+
+    /// Returns `true` if a new release is downloaded and ready to be installed.
+    /// Returns error when the updater is disabled.
+    #[rpc(name = "parity_upgradeReady")]
+    fn upgrade_ready(&self) -> Result<bool>;
+
     /// Removes transaction from transaction queue.
     /// Makes sense only for transactions that were not propagated to other peers yet
     /// like scheduled transactions or transactions in future.
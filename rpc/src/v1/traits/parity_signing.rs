@@ -19,7 +19,7 @@ use jsonrpc_core::{BoxFuture, Result};
 use jsonrpc_derive::rpc;
 
 use ethereum_types::{H160, U256};
-use v1::types::{Bytes, ConfirmationResponse, Either, TransactionRequest};
+use v1::types::{Bytes, ConfirmationResponse, Either, SentTransaction, TransactionRequest};
 
 /// Signing methods implementation.
 #[rpc(server)]
@@ -36,6 +36,15 @@ pub trait ParitySigning {
         _: TransactionRequest,
     ) -> BoxFuture<TransactionRequest>;
 
+    /// Signs and sends a transaction from an unlocked account.
+    /// Returns the transaction hash along with the nonce, gas and gas price that were used.
+    #[rpc(meta, name = "parity_sendTransactionDetailed")]
+    fn send_transaction_detailed(
+        &self,
+        _: Self::Metadata,
+        _: TransactionRequest,
+    ) -> BoxFuture<SentTransaction>;
+
     /// Posts sign request asynchronously.
     /// Will return a confirmation ID for later use with check_transaction.
     #[rpc(meta, name = "parity_postSign")]
@@ -58,6 +58,30 @@ pub trait Signer {
     #[rpc(name = "signer_confirmRequestRaw")]
     fn confirm_request_raw(&self, _: U256, _: Bytes) -> Result<ConfirmationResponse>;
 
+    /// Returns the requests waiting in the signer queue, including their origin and the
+    /// time they were queued.
+    #[rpc(name = "parity_signerRequestsToConfirm")]
+    fn parity_requests_to_confirm(&self) -> Result<Vec<ConfirmationRequest>>;
+
+    /// Signs a queued request with the given account password and resolves it,
+    /// applying the modifications to a transaction request first.
+    #[rpc(name = "parity_confirmRequest")]
+    fn parity_confirm_request(
+        &self,
+        _: U256,
+        _: TransactionModification,
+        _: String,
+    ) -> BoxFuture<ConfirmationResponse>;
+
+    /// Resolves a queued request with data signed outside of the node.
+    #[rpc(name = "parity_confirmRequestRaw")]
+    fn parity_confirm_request_raw(&self, _: U256, _: Bytes) -> Result<ConfirmationResponse>;
+
+    /// Removes a request from the queue, resolving it as rejected.
+    /// Returns false if there is no such request.
+    #[rpc(name = "parity_rejectRequest")]
+    fn parity_reject_request(&self, _: U256) -> Result<bool>;
+
     /// Reject the confirmation request.
     #[rpc(name = "signer_rejectRequest")]
     fn reject_request(&self, _: U256) -> Result<bool>;
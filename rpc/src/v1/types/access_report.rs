@@ -0,0 +1,70 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of OpenEthereum.
+
+// OpenEthereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// OpenEthereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! State accessed by a call.
+
+use std::collections::BTreeMap;
+
+use ethcore::client::Executed;
+use ethereum_types::{H160, H256};
+use v1::types::Bytes;
+
+/// Result of `parity_callWithAccessReport`.
+///
+/// Written slots are taken from the state diff of the call, so a slot that is stored to
+/// but ends up with its original value (e.g. an `SSTORE` writing back the same value) is
+/// reported as read, not written.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AccessReport {
+    /// Output data, or revert data if the call reverted.
+    pub output: Bytes,
+    /// Accounts accessed by the call, with the storage keys accessed without being modified.
+    pub read: BTreeMap<H160, Vec<H256>>,
+    /// Accounts modified by the call, with the storage keys modified.
+    pub written: BTreeMap<H160, Vec<H256>>,
+}
+
+impl From<Executed> for AccessReport {
+    fn from(executed: Executed) -> Self {
+        let written: BTreeMap<H160, Vec<H256>> = executed
+            .state_diff
+            .map(|diff| {
+                diff.raw
+                    .into_iter()
+                    .map(|(address, diff)| (address, diff.storage.keys().cloned().collect()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let read = executed
+            .accessed
+            .into_iter()
+            .map(|(address, keys)| {
+                let modified = written.get(&address);
+                let keys = keys
+                    .into_iter()
+                    .filter(|key| modified.map_or(true, |modified| !modified.contains(key)))
+                    .collect();
+                (address, keys)
+            })
+            .collect();
+
+        AccessReport {
+            output: executed.output.into(),
+            read,
+            written,
+        }
+    }
+}
@@ -60,6 +60,30 @@ pub struct ExtAccountInfo {
     pub uuid: Option<String>,
 }
 
+/// Where the key of an account is kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AccountKind {
+    /// Key stored in the local keystore.
+    Keystore,
+    /// Address book entry without a key.
+    WatchOnly,
+}
+
+/// Account information with its kind (used by `parity_getAllAccountsInfo`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FullAccountInfo {
+    /// Account name
+    pub name: String,
+    /// Account meta JSON
+    pub meta: String,
+    /// Account UUID (`None` for non-keystore accounts)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// Kind of the account
+    pub kind: AccountKind,
+}
+
 /// account derived from a signature
 /// as well as information that tells if it is valid for
 /// the current chain
@@ -0,0 +1,35 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of OpenEthereum.
+
+// OpenEthereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// OpenEthereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Rewards earned by sealing a block.
+
+use ethereum_types::U256;
+
+/// Result of `parity_getBlockReward`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockReward {
+    /// Base reward of the block author.
+    pub block_reward: U256,
+    /// Additional reward of the block author for including uncles.
+    pub uncle_inclusion_reward: U256,
+    /// Fees paid by the block's transactions to the block author.
+    pub transaction_fees: U256,
+    /// Rewards of the uncle authors, in order of inclusion.
+    pub uncle_rewards: Vec<U256>,
+    /// Total earnings of the block author.
+    pub total: U256,
+}
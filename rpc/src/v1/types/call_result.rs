@@ -0,0 +1,50 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of OpenEthereum.
+
+// OpenEthereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// OpenEthereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Detailed result of a call.
+
+use ethcore::client::Executed;
+use ethereum_types::U256;
+use v1::types::Bytes;
+
+/// Result of `parity_callDetailed`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallResult {
+    /// Output data, or revert data if the call reverted.
+    pub output: Bytes,
+    /// Gas used by the call, after refunds.
+    pub gas_used: U256,
+    /// Gas refunded at the end of the call.
+    pub gas_refunded: U256,
+    /// Whether the call completed without an exception.
+    pub success: bool,
+    /// Description of the exception, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<Executed> for CallResult {
+    fn from(executed: Executed) -> Self {
+        CallResult {
+            output: executed.output.into(),
+            gas_used: executed.gas_used,
+            gas_refunded: executed.refunded,
+            success: executed.exception.is_none(),
+            error: executed.exception.map(|e| e.to_string()),
+        }
+    }
+}
@@ -38,6 +38,8 @@ pub struct ConfirmationRequest {
     pub payload: ConfirmationPayload,
     /// Request origin
     pub origin: Origin,
+    /// Time the request was queued, in seconds since the UNIX epoch
+    pub timestamp: u64,
 }
 
 impl From<helpers::ConfirmationRequest> for ConfirmationRequest {
@@ -46,6 +48,7 @@ impl From<helpers::ConfirmationRequest> for ConfirmationRequest {
             id: c.id,
             payload: c.payload.into(),
             origin: c.origin,
+            timestamp: c.timestamp,
         }
     }
 }
@@ -314,11 +317,12 @@ mod tests {
             id: 15.into(),
             payload: helpers::ConfirmationPayload::EthSignMessage(1.into(), vec![5].into()),
             origin: Origin::Rpc("test service".into()),
+            timestamp: 0,
         };
 
         // when
         let res = serde_json::to_string(&ConfirmationRequest::from(request));
-        let expected = r#"{"id":"0xf","payload":{"sign":{"address":"0x0000000000000000000000000000000000000001","data":"0x05"}},"origin":{"rpc":"test service"}}"#;
+        let expected = r#"{"id":"0xf","payload":{"sign":{"address":"0x0000000000000000000000000000000000000001","data":"0x05"}},"origin":{"rpc":"test service"},"timestamp":0}"#;
 
         // then
         assert_eq!(res.unwrap(), expected.to_owned());
@@ -345,11 +349,12 @@ mod tests {
                 },
             ),
             origin: Origin::Signer { session: 5.into() },
+            timestamp: 0,
         };
 
         // when
         let res = serde_json::to_string(&ConfirmationRequest::from(request));
-        let expected = r#"{"id":"0xf","payload":{"sendTransaction":{"from":"0x0000000000000000000000000000000000000000","to":null,"gasPrice":"0x2710","gas":"0x3a98","value":"0x186a0","data":"0x010203","nonce":"0x1","condition":null}},"origin":{"signer":{"session":"0x0000000000000000000000000000000000000000000000000000000000000005"}}}"#;
+        let expected = r#"{"id":"0xf","payload":{"sendTransaction":{"from":"0x0000000000000000000000000000000000000000","to":null,"gasPrice":"0x2710","gas":"0x3a98","value":"0x186a0","data":"0x010203","nonce":"0x1","condition":null}},"origin":{"signer":{"session":"0x0000000000000000000000000000000000000000000000000000000000000005"}},"timestamp":0}"#;
 
         // then
         assert_eq!(res.unwrap(), expected.to_owned());
@@ -376,11 +381,12 @@ mod tests {
                 },
             ),
             origin: Origin::Unknown,
+            timestamp: 0,
         };
 
         // when
         let res = serde_json::to_string(&ConfirmationRequest::from(request));
-        let expected = r#"{"id":"0xf","payload":{"signTransaction":{"from":"0x0000000000000000000000000000000000000000","to":null,"gasPrice":"0x2710","gas":"0x3a98","value":"0x186a0","data":"0x010203","nonce":"0x1","condition":null}},"origin":"unknown"}"#;
+        let expected = r#"{"id":"0xf","payload":{"signTransaction":{"from":"0x0000000000000000000000000000000000000000","to":null,"gasPrice":"0x2710","gas":"0x3a98","value":"0x186a0","data":"0x010203","nonce":"0x1","condition":null}},"origin":"unknown","timestamp":0}"#;
 
         // then
         assert_eq!(res.unwrap(), expected.to_owned());
@@ -393,11 +399,12 @@ mod tests {
             id: 15.into(),
             payload: helpers::ConfirmationPayload::Decrypt(10.into(), vec![1, 2, 3].into()),
             origin: Default::default(),
+            timestamp: 0,
         };
 
         // when
         let res = serde_json::to_string(&ConfirmationRequest::from(request));
-        let expected = r#"{"id":"0xf","payload":{"decrypt":{"address":"0x000000000000000000000000000000000000000a","msg":"0x010203"}},"origin":"unknown"}"#;
+        let expected = r#"{"id":"0xf","payload":{"decrypt":{"address":"0x000000000000000000000000000000000000000a","msg":"0x010203"}},"origin":"unknown","timestamp":0}"#;
 
         // then
         assert_eq!(res.unwrap(), expected.to_owned());
@@ -0,0 +1,66 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of OpenEthereum.
+
+// OpenEthereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// OpenEthereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Consensus capability of the node.
+
+use ethcore::spec;
+
+/// Capability info of the node with respect to the forks announced by the chain.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConsensusCapability {
+    /// Unknown; the chain spec doesn't announce which forks it schedules.
+    Unknown,
+    /// Capable of consensus indefinitely.
+    Capable,
+    /// Capable of consensus up until a definite block.
+    CapableUntil(u64),
+    /// Incapable of consensus since a particular block.
+    IncapableSince(u64),
+}
+
+impl From<spec::ConsensusCapability> for ConsensusCapability {
+    fn from(c: spec::ConsensusCapability) -> Self {
+        match c {
+            spec::ConsensusCapability::Unknown => ConsensusCapability::Unknown,
+            spec::ConsensusCapability::Capable => ConsensusCapability::Capable,
+            spec::ConsensusCapability::CapableUntil(n) => ConsensusCapability::CapableUntil(n),
+            spec::ConsensusCapability::IncapableSince(n) => ConsensusCapability::IncapableSince(n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConsensusCapability;
+    use serde_json;
+
+    #[test]
+    fn consensus_capability() {
+        assert_eq!(
+            serde_json::to_string(&ConsensusCapability::Capable).unwrap(),
+            r#""capable""#
+        );
+        assert_eq!(
+            serde_json::to_string(&ConsensusCapability::CapableUntil(15)).unwrap(),
+            r#"{"capableUntil":15}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ConsensusCapability::IncapableSince(15)).unwrap(),
+            r#"{"incapableSince":15}"#
+        );
+    }
+}
@@ -19,12 +19,17 @@
 #[cfg(test)]
 mod eth_types;
 
+mod access_report;
 mod account_info;
 mod block;
 mod block_number;
+mod block_reward;
 mod bytes;
 mod call_request;
+mod call_result;
 mod confirmations;
+mod consensus_status;
+mod dapps;
 mod derivation;
 mod eip191;
 mod filter;
@@ -47,16 +52,24 @@ mod work;
 pub mod pubsub;
 
 pub use self::{
-    account_info::{AccountInfo, EthAccount, ExtAccountInfo, RecoveredAccount, StorageProof},
+    access_report::AccessReport,
+    account_info::{
+        AccountInfo, AccountKind, EthAccount, ExtAccountInfo, FullAccountInfo, RecoveredAccount,
+        StorageProof,
+    },
     block::{Block, BlockTransactions, Header, Rich, RichBlock, RichHeader},
     block_number::{block_number_to_id, BlockNumber},
+    block_reward::BlockReward,
     bytes::Bytes,
     call_request::CallRequest,
+    call_result::CallResult,
     confirmations::{
         ConfirmationPayload, ConfirmationRequest, ConfirmationResponse,
         ConfirmationResponseWithToken, DecryptRequest, EIP191SignRequest, Either, EthSignRequest,
         TransactionModification,
     },
+    consensus_status::ConsensusCapability,
+    dapps::LocalDapp,
     derivation::{Derive, DeriveHash, DeriveHierarchical},
     eip191::{EIP191Version, PresignedTransaction},
     filter::{Filter, FilterChanges},
@@ -65,7 +78,7 @@ pub use self::{
     log::Log,
     node_kind::{Availability, Capability, NodeKind},
     provenance::Origin,
-    receipt::Receipt,
+    receipt::{InternalTransaction, Receipt},
     rpc_settings::RpcSettings,
     secretstore::EncryptedDocumentKey,
     sync::{
@@ -74,7 +87,10 @@ pub use self::{
     },
     trace::{LocalizedTrace, TraceResults, TraceResultsWithTransactionHash},
     trace_filter::TraceFilter,
-    transaction::{LocalTransactionStatus, RichRawTransaction, Transaction},
+    transaction::{
+        LocalTransactionStatus, RichRawTransaction, SentTransaction, SubmittedTransaction,
+        Transaction,
+    },
     transaction_condition::TransactionCondition,
     transaction_request::TransactionRequest,
     work::Work,
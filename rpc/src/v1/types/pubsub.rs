@@ -19,7 +19,7 @@
 use ethereum_types::H256;
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{from_value, Value};
-use v1::types::{Filter, Log, RichHeader};
+use v1::types::{Filter, Log, RichHeader, SyncStatus};
 
 /// Subscription result.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -30,6 +30,8 @@ pub enum Result {
     Log(Box<Log>),
     /// Transaction hash
     TransactionHash(H256),
+    /// Sync status
+    Syncing(SyncStatus),
 }
 
 impl Serialize for Result {
@@ -41,6 +43,7 @@ impl Serialize for Result {
             Result::Header(ref header) => header.serialize(serializer),
             Result::Log(ref log) => log.serialize(serializer),
             Result::TransactionHash(ref hash) => hash.serialize(serializer),
+            Result::Syncing(ref status) => status.serialize(serializer),
         }
     }
 }
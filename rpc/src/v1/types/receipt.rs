@@ -14,12 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with OpenEthereum.  If not, see <http://www.gnu.org/licenses/>.
 
+use ethcore::trace::{
+    trace::{Action, Res},
+    LocalizedTrace,
+};
 use ethereum_types::{Bloom as H2048, H160, H256, U256, U64};
 use types::{
     receipt::{LocalizedReceipt, RichReceipt, TransactionOutcome, TypedReceipt},
     transaction::TypedTxId,
 };
 use v1::types::Log;
+use vm::CallType;
 
 /// Receipt
 #[derive(Debug, Serialize)]
@@ -58,6 +63,82 @@ pub struct Receipt {
     // NOTE(niklasad1): Unknown after EIP98 rules, if it's missing then skip serializing it
     #[serde(skip_serializing_if = "Option::is_none", rename = "status")]
     pub status_code: Option<U64>,
+    /// Reason the transaction was reverted, if requested and the transaction failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<String>,
+    /// Value transfers made by internal calls (only in `parity_transactionReceipt`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal_transactions: Option<Vec<InternalTransaction>>,
+}
+
+/// Value transfer made by an internal call.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InternalTransaction {
+    /// Sender
+    pub from: H160,
+    /// Recipient
+    pub to: H160,
+    /// Transferred value
+    pub value: U256,
+}
+
+impl InternalTransaction {
+    /// Returns value transfers made by the traced subcalls of a transaction.
+    ///
+    /// Transfers made by calls, creations and self-destructs are reported,
+    /// unless the trace or any of its ancestors failed and was reverted.
+    /// The outermost trace is the transaction itself and is skipped.
+    pub fn from_traces(traces: &[LocalizedTrace]) -> Vec<Self> {
+        let mut failed: Vec<&[usize]> = Vec::new();
+        traces
+            .iter()
+            .filter_map(|trace| {
+                if failed
+                    .iter()
+                    .any(|address| trace.trace_address.starts_with(address))
+                {
+                    return None;
+                }
+                match trace.result {
+                    Res::FailedCall(_) | Res::FailedCreate(_) => {
+                        failed.push(&trace.trace_address);
+                        None
+                    }
+                    _ => Self::from_trace(trace),
+                }
+            })
+            .collect()
+    }
+
+    fn from_trace(trace: &LocalizedTrace) -> Option<Self> {
+        if trace.trace_address.is_empty() {
+            return None;
+        }
+        let transfer = match (&trace.action, &trace.result) {
+            (&Action::Call(ref call), &Res::Call(_)) if call.call_type == CallType::Call => {
+                InternalTransaction {
+                    from: call.from,
+                    to: call.to,
+                    value: call.value,
+                }
+            }
+            (&Action::Create(ref create), &Res::Create(ref result)) => InternalTransaction {
+                from: create.from,
+                to: result.address,
+                value: create.value,
+            },
+            (&Action::Suicide(ref suicide), _) => InternalTransaction {
+                from: suicide.address,
+                to: suicide.refund_address,
+                value: suicide.balance,
+            },
+            _ => return None,
+        };
+        if transfer.value.is_zero() {
+            return None;
+        }
+        Some(transfer)
+    }
 }
 
 impl Receipt {
@@ -93,6 +174,8 @@ impl From<LocalizedReceipt> for Receipt {
             status_code: Self::outcome_to_status_code(&r.outcome),
             state_root: Self::outcome_to_state_root(r.outcome),
             logs_bloom: r.log_bloom,
+            revert_reason: None,
+            internal_transactions: None,
         }
     }
 }
@@ -114,6 +197,8 @@ impl From<RichReceipt> for Receipt {
             status_code: Self::outcome_to_status_code(&r.outcome),
             state_root: Self::outcome_to_state_root(r.outcome),
             logs_bloom: r.log_bloom,
+            revert_reason: None,
+            internal_transactions: None,
         }
     }
 }
@@ -137,14 +222,21 @@ impl From<TypedReceipt> for Receipt {
             status_code: Self::outcome_to_status_code(&r.outcome),
             state_root: Self::outcome_to_state_root(r.outcome),
             logs_bloom: r.log_bloom,
+            revert_reason: None,
+            internal_transactions: None,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use ethcore::trace::{
+        trace::{Action, Call, CallResult, Create, CreateResult, Res, Suicide},
+        LocalizedTrace, TraceError,
+    };
     use serde_json;
-    use v1::types::{Log, Receipt};
+    use v1::types::{InternalTransaction, Log, Receipt};
+    use vm::CallType;
 
     #[test]
     fn receipt_serialization() {
@@ -192,9 +284,92 @@ mod tests {
             logs_bloom: 15.into(),
             state_root: Some(10.into()),
             status_code: Some(1u64.into()),
+            revert_reason: None,
+            internal_transactions: None,
         };
 
         let serialized = serde_json::to_string(&receipt).unwrap();
         assert_eq!(serialized, s);
     }
+
+    #[test]
+    fn internal_transactions_from_traces() {
+        let trace = |trace_address: Vec<usize>, action: Action, result: Res| LocalizedTrace {
+            action,
+            result,
+            subtraces: 0,
+            trace_address,
+            transaction_number: Some(0),
+            transaction_hash: Some(1.into()),
+            block_number: 0,
+            block_hash: 3.into(),
+        };
+        let call = |from: u64, to: u64, value: u64| {
+            Action::Call(Call {
+                from: from.into(),
+                to: to.into(),
+                value: value.into(),
+                gas: 0x100.into(),
+                input: vec![],
+                call_type: CallType::Call,
+            })
+        };
+        let called = || {
+            Res::Call(CallResult {
+                gas_used: 0x10.into(),
+                output: vec![],
+            })
+        };
+        let traces = vec![
+            trace(vec![], call(9, 10, 0), called()),
+            // contract creation endowed with value
+            trace(
+                vec![0],
+                Action::Create(Create {
+                    from: 10.into(),
+                    value: 4.into(),
+                    gas: 0x100.into(),
+                    init: vec![],
+                }),
+                Res::Create(CreateResult {
+                    gas_used: 0x10.into(),
+                    code: vec![],
+                    address: 11.into(),
+                }),
+            ),
+            // self-destruct sending the balance to the heir
+            trace(
+                vec![1],
+                Action::Suicide(Suicide {
+                    address: 10.into(),
+                    refund_address: 12.into(),
+                    balance: 2.into(),
+                }),
+                Res::None,
+            ),
+            // reverted call, its transfers are undone along with its subcalls
+            trace(
+                vec![2],
+                call(10, 13, 5),
+                Res::FailedCall(TraceError::Reverted),
+            ),
+            trace(vec![2, 0], call(13, 14, 1), called()),
+        ];
+
+        assert_eq!(
+            InternalTransaction::from_traces(&traces),
+            vec![
+                InternalTransaction {
+                    from: 10.into(),
+                    to: 11.into(),
+                    value: 4.into(),
+                },
+                InternalTransaction {
+                    from: 10.into(),
+                    to: 12.into(),
+                    value: 2.into(),
+                },
+            ]
+        );
+    }
 }
@@ -180,6 +180,43 @@ impl RichRawTransaction {
     }
 }
 
+/// Hash of a dispatched transaction along with the values filled in by the node.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SentTransaction {
+    /// Transaction hash
+    pub hash: H256,
+    /// Nonce assigned to the transaction
+    pub nonce: U256,
+    /// Gas limit
+    pub gas: U256,
+    /// Gas price
+    pub gas_price: U256,
+}
+
+impl SentTransaction {
+    /// Creates new `SentTransaction` from `SignedTransaction`.
+    pub fn from_signed(tx: &SignedTransaction) -> Self {
+        SentTransaction {
+            hash: tx.hash(),
+            nonce: tx.tx().nonce,
+            gas: tx.tx().gas,
+            gas_price: tx.tx().gas_price,
+        }
+    }
+}
+
+/// Hash of a submitted raw transaction along with the state of the queue it was submitted to.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmittedTransaction {
+    /// Transaction hash
+    pub hash: H256,
+    /// Queue occupancy (in percent) if the queue is under pressure and clients should back off.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue_pressure: Option<usize>,
+}
+
 impl Transaction {
     /// Convert `LocalizedTransaction` into RPC Transaction.
     pub fn from_localized(mut t: LocalizedTransaction) -> Transaction {